@@ -0,0 +1,53 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::execute::validate_and_get_forward;
+use crate::state::Forward;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetAuthorityInstruction {
+    execute_requires_authority: bool,
+}
+
+/**
+ * Set the authority of the forward, and whether execute requires the authority's signature
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The current authority (signer)
+ *  - accounts[2] The new authority
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetAuthorityInstruction,
+) -> ProgramResult {
+
+    msg!("Set authority instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let new_authority = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    forward.authority = *new_authority.key;
+    forward.execute_requires_authority = instr.execute_requires_authority;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
+
+    Ok(())
+}
+
+pub fn validate_authority(forward: &Forward, authority: &AccountInfo) -> ProgramResult {
+    assert_that("Authority is signer", authority.is_signer, ProgramError::MissingRequiredSignature)?;
+    assert_that("Authority is valid", *authority.key == forward.authority, ProgramError::from(ForwardError::InvalidAuthority))
+}
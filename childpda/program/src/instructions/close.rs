@@ -0,0 +1,116 @@
+use std::slice::Iter;
+
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_token_2022::{check_spl_token_program_account, check_system_program_account};
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{close_forward_ata, forward_sol, forward_token, is_emptied, next_destination_accounts, next_mint_token_program, next_token_targets, next_transfer_hook_accounts, validate_and_get_forward, validate_not_before, validate_vested};
+use crate::state::{Forward, MintPolicy};
+
+/**
+ * Close the forward, forwarding any remaining sol and tokens to the recipients and returning the rent
+ * A time locked forward cannot be closed until its not before has passed, nor a vesting forward until its vesting has ended
+ * A paused forward cannot be closed until it is unpaused
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward pda
+ *  - accounts[2..n+1] The destination account of each of the n recipients, in order
+ *  - accounts[n+2] The system account
+ *  - accounts[n+3] The forward authority (signer, will pay for the destination atas to be created if they do not exist)
+ *  - accounts[n+4] The rent recipient, receives the rent of the forward account and the closed forward ATAs
+ *  - If the forward holds tokens, the following accounts are required
+ *      - accounts[n+5] The token program account
+ *      - accounts[n+6] The associated token program account
+ *
+ *      - Followed by the following 2 + n accounts for each mint/token held by the forward:
+ *      - The mint account
+ *      - The token program of the mint, only if the mint is owned by the other token program
+ *      - The forward ATA account, closed once emptied, it is left open while it holds withheld transfer fees
+ *      - The destination ATA account of each recipient, in order
+ *      - A mint with a route has its route destination account and route destination ATA in place of the recipient ATAs
+ *      - A mint with a transfer hook is then followed by the hook program, its extra account metas account and each extra account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn close(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Close forward instruction, accounts {}", accounts.len());
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let forward_pda = next_account_info(accounts_iter)?;
+    let forward = validate_and_get_forward(program_id, &forward_account)?;
+
+    let recipients = forward.recipients.len();
+    assert_that("Valid number of accounts",
+                accounts.len() == 5 + recipients || accounts.len() >= 9 + recipients,
+                ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
+
+    let destination_accounts = next_destination_accounts(&forward, accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let rent_recipient = next_account_info(accounts_iter)?;
+
+    check_system_program_account(system_program.key)?;
+    assert_that("Forward pda belongs to this account", &forward.forward_pda == forward_pda.key, ProgramError::from(ForwardError::InvalidForwardAddress))?;
+    validate_authority(&forward, authority)?;
+    assert_that("Forward is not paused", !forward.paused, ProgramError::from(ForwardError::ForwardPaused))?;
+    validate_not_before(&forward)?;
+    validate_vested(&forward)?;
+
+    maybe_close_tokens(&forward, forward_account, forward_pda, &destination_accounts, system_program, authority, rent_recipient, accounts_iter)
+        .and_then(|_| forward_sol(&forward, forward_account, forward_pda, &destination_accounts, &forward.recipients, forward.dust_recipient, 0, None, None, None, None))
+        .and_then(|_| close_forward_account(forward_account, rent_recipient))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn maybe_close_tokens<'a>(
+    forward: &Forward,
+    forward_account: &AccountInfo<'a>,
+    forward_pda: &AccountInfo<'a>,
+    target_accounts: &[&AccountInfo<'a>],
+    system_program: &AccountInfo<'a>,
+    signer: &AccountInfo<'a>,
+    rent_recipient: &AccountInfo<'a>,
+    accounts_iter: &mut Iter<AccountInfo<'a>>,
+) -> ProgramResult {
+
+    if let (Some(token_program), Some(ata_program)) = (accounts_iter.next(), accounts_iter.next()) {
+
+        check_spl_token_program_account(token_program.key)?;
+        assert_that("Associated token account id is correct", spl_associated_token_account::check_id(ata_program.key), ProgramError::IncorrectProgramId)?;
+
+        //Thresholds, the mint policy, the ended vesting and the rate limit do not apply, the forward ATAs must be emptied before they can be closed
+        while let Some(mint) = accounts_iter.next() {
+
+            let mint_token_program = next_mint_token_program(token_program, mint, accounts_iter)?;
+            let forward_ata = next_account_info(accounts_iter)?;
+            let targets = next_token_targets(forward, mint.key, target_accounts, accounts_iter)?;
+            let transfer_hook_accounts = next_transfer_hook_accounts(mint, accounts_iter)?;
+            forward_token(forward, forward_account, forward_pda, &targets, &transfer_hook_accounts, system_program, signer, &mint_token_program, ata_program, mint, forward_ata, 0, &MintPolicy::None, None, None, None)?;
+            //Token 2022 refuses to close an ATA holding withheld transfer fees, it is left open until they are harvested
+            if is_emptied(forward_ata)? {
+                close_forward_ata(forward, forward_account, forward_pda, &mint_token_program, forward_ata, rent_recipient)?;
+            }
+
+        }
+    }
+    Ok(())
+}
+
+fn close_forward_account(forward_account: &AccountInfo, rent_recipient: &AccountInfo) -> ProgramResult {
+    let rent_balance = forward_account.lamports();
+    **forward_account.try_borrow_mut_lamports()? = 0;
+    **rent_recipient.try_borrow_mut_lamports()? = rent_recipient.lamports().checked_add(rent_balance).ok_or(ForwardError::OverflowError)?;
+    forward_account.data.borrow_mut().fill(0);
+    Ok(())
+}
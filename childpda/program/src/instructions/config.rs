@@ -0,0 +1,161 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::state::Config;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct InitConfigInstruction {
+    bump: u8,
+    max_mints_per_execute: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct UpdateConfigInstruction {
+    admin: Pubkey,
+    emergency_stop: bool,
+    max_mints_per_execute: u8,
+}
+
+/**
+ * Initialise the program config, only the program upgrade authority can initialise it and it becomes the admin
+ * The config account may already hold lamports, it is topped up to the rent minimum, allocated and assigned to the program
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The config account, derived from the config seed with the canonical bump
+ *  - accounts[1] The admin account (signer, the program upgrade authority, pays for the config account)
+ *  - accounts[2] The program data account of the program
+ *  - accounts[3] The system program account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn init_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: InitConfigInstruction,
+) -> ProgramResult {
+
+    msg!("Init config instruction");
+    let accounts_iter = &mut accounts.iter();
+    let config_account = next_account_info(accounts_iter)?;
+    let admin = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    assert_that("System program is correct", system_account.key == &solana_program::system_program::id(), ProgramError::IncorrectProgramId)?;
+    assert_that("Admin is signer", admin.is_signer, ProgramError::MissingRequiredSignature)?;
+    validate_upgrade_authority(program_id, program_data, admin)?;
+    assert_that("Config does not exist",
+                config_account.owner == system_account.key && config_account.data_is_empty(),
+                ProgramError::from(ForwardError::InvalidConfig))?;
+    assert_that("Max mints per execute is positive", instr.max_mints_per_execute > 0, ProgramError::from(ForwardError::InvalidConfig))?;
+    //Only the canonical config pda is accepted, execute falls back to the default config at the canonical address
+    let (config_pda, config_bump) = Pubkey::find_program_address(&[Config::CONFIG_SEED], program_id);
+    assert_that("Config address is the canonical config pda",
+                config_pda == *config_account.key && instr.bump == config_bump,
+                ProgramError::from(ForwardError::InvalidConfig))?;
+
+    let rent_top_up = Rent::get()?.minimum_balance(Config::LEN).saturating_sub(config_account.lamports());
+    if rent_top_up > 0 {
+        invoke(&system_instruction::transfer(admin.key, config_account.key, rent_top_up),
+               &[admin.clone(), config_account.clone(), system_account.clone()])?;
+    }
+    let config_seeds: &[&[u8]] = &[Config::CONFIG_SEED, &[instr.bump]];
+    invoke_signed(&system_instruction::allocate(config_account.key, Config::LEN.try_into().unwrap()),
+                  &[config_account.clone(), system_account.clone()], &[config_seeds])?;
+    invoke_signed(&system_instruction::assign(config_account.key, program_id),
+                  &[config_account.clone(), system_account.clone()], &[config_seeds])?;
+
+    Config::new(instr.bump, *admin.key, instr.max_mints_per_execute).serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/**
+ * The admin must be the upgrade authority recorded in the program data account of the program
+ */
+fn validate_upgrade_authority(program_id: &Pubkey, program_data: &AccountInfo, admin: &AccountInfo) -> ProgramResult {
+    let (program_data_address, _) = Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    assert_that("Program data account is valid",
+                *program_data.key == program_data_address && *program_data.owner == bpf_loader_upgradeable::id(),
+                ProgramError::InvalidAccountData)?;
+    let upgrade_authority = match bincode::deserialize::<UpgradeableLoaderState>(&program_data.data.borrow()) {
+        Ok(UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. }) => upgrade_authority_address,
+        _ => None,
+    };
+    assert_that("Admin is the program upgrade authority", upgrade_authority == Some(*admin.key), ProgramError::from(ForwardError::InvalidAuthority))
+}
+
+/**
+ * Update the program config, only the admin can update it
+ * While the emergency stop is set, every instruction other than the config instructions fails
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The config account
+ *  - accounts[1] The admin account (signer)
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn update_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: UpdateConfigInstruction,
+) -> ProgramResult {
+
+    msg!("Update config instruction");
+    let accounts_iter = &mut accounts.iter();
+    let config_account = next_account_info(accounts_iter)?;
+    let admin = next_account_info(accounts_iter)?;
+
+    assert_that("Config is initialised", config_account.owner == program_id, ProgramError::from(ForwardError::InvalidConfig))?;
+    let mut config = validate_and_get_config(program_id, config_account)?;
+    assert_that("Admin is signer", admin.is_signer, ProgramError::MissingRequiredSignature)?;
+    assert_that("Admin is valid", *admin.key == config.admin, ProgramError::from(ForwardError::InvalidAuthority))?;
+    assert_that("Max mints per execute is positive", instr.max_mints_per_execute > 0, ProgramError::from(ForwardError::InvalidConfig))?;
+
+    config.admin = instr.admin;
+    config.emergency_stop = instr.emergency_stop;
+    config.max_mints_per_execute = instr.max_mints_per_execute;
+    config.serialize(&mut &mut config_account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/**
+ * Until the config account is initialised the default config applies
+ */
+pub fn validate_and_get_config(program_id: &Pubkey, config_account: &AccountInfo) -> Result<Config, ProgramError> {
+    if config_account.owner != program_id {
+        let (config_pda, _) = Pubkey::find_program_address(&[Config::CONFIG_SEED], program_id);
+        assert_that("Config address is valid", config_pda == *config_account.key && config_account.data_is_empty(), ProgramError::from(ForwardError::InvalidConfig))?;
+        return Ok(Config::default());
+    }
+
+    let config = Config::unpack(&config_account.data.borrow())?;
+    let config_pda_check = Pubkey::create_program_address(&[Config::CONFIG_SEED, &[config.bump]], program_id);
+    assert_that("Config address is valid",
+                config_pda_check.is_ok_and(|config_pda| config_pda == *config_account.key),
+                ProgramError::from(ForwardError::InvalidConfig))?;
+    Ok(config)
+}
+
+/**
+ * Every instruction other than the config instructions is followed by the config account, and fails while the emergency stop is set
+ */
+pub fn split_config<'a, 'b>(program_id: &Pubkey, accounts: &'b [AccountInfo<'a>]) -> Result<(Config, &'b [AccountInfo<'a>]), ProgramError> {
+    let (config_account, accounts) = accounts.split_last().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let config = validate_and_get_config(program_id, config_account)?;
+    assert_that("Emergency stop is not set", !config.emergency_stop, ProgramError::from(ForwardError::EmergencyStop))?;
+    Ok((config, accounts))
+}
@@ -1,19 +1,50 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::{AccountInfo, next_account_info}, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
+use solana_program::{account_info::{AccountInfo, next_account_info}, clock::Clock, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
 use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use spl_token::state::Account as SplTokenAccount;
 use spl_token_2022::check_system_program_account;
+use spl_token_2022::extension::StateWithExtensions;
 use spl_token_2022::state::Account as SplToken2022Account;
 
 use crate::errors::{assert_that, ForwardError};
-use crate::state::Forward;
+use crate::state::{Forward, NotBefore, Recipient, Stats};
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct CreateForwardInstruction {
     forward_pda: Pubkey,
     bump: u8,
+    authority: Pubkey,
+    execute_requires_authority: bool,
+    recipient_bps: Vec<u16>,
+    dust_recipient: u8,
+    not_before: Option<NotBefore>,
+}
+
+//The original layout, without a time lock
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateForwardV1Instruction {
+    forward_pda: Pubkey,
+    bump: u8,
+    authority: Pubkey,
+    execute_requires_authority: bool,
+    recipient_bps: Vec<u16>,
+    dust_recipient: u8,
+}
+
+impl From<CreateForwardV1Instruction> for CreateForwardInstruction {
+    fn from(instr: CreateForwardV1Instruction) -> Self {
+        CreateForwardInstruction {
+            forward_pda: instr.forward_pda,
+            bump: instr.bump,
+            authority: instr.authority,
+            execute_requires_authority: instr.execute_requires_authority,
+            recipient_bps: instr.recipient_bps,
+            dust_recipient: instr.dust_recipient,
+            not_before: None,
+        }
+    }
 }
 
 pub fn create(
@@ -25,28 +56,52 @@ pub fn create(
     let accounts_iter = &mut accounts.iter();
     let forward_account = next_account_info(accounts_iter)?;
     let destination_account = next_account_info(accounts_iter)?;
+    let quarantine_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
+    let destination_accounts = [destination_account].into_iter().chain(accounts_iter).collect::<Vec<_>>();
 
-    validate(program_id, forward_account, destination_account, system_program, &instr)
+    validate(program_id, forward_account, &destination_accounts, quarantine_account, system_program, &instr)
         .and_then(|_|
-            create_forward_account(&program_id, &forward_account, &destination_account.key, &system_program, &payer, &instr))
+            create_forward_account(program_id, forward_account, &destination_accounts, quarantine_account.key, system_program, payer, &instr))
 }
 
 fn create_forward_account<'a>(
     program_id: &Pubkey,
     forward_account: &AccountInfo<'a>,
-    destination_key: &Pubkey,
+    destination_accounts: &[&AccountInfo<'a>],
+    quarantine_key: &Pubkey,
     system_program: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
     instr: &CreateForwardInstruction
 ) -> ProgramResult {
 
+    let recipients = destination_accounts.iter()
+        .zip(instr.recipient_bps.iter())
+        .map(|(destination_account, bps)| Recipient::new(*destination_account.key, *bps))
+        .collect();
+
+    let forward = Forward {
+        not_before: instr.not_before,
+        stats: Stats::new(Clock::get()?.unix_timestamp),
+        ..Forward::new(
+            instr.forward_pda,
+            instr.bump,
+            instr.authority,
+            *quarantine_key,
+            instr.execute_requires_authority,
+            recipients,
+            instr.dust_recipient,
+        )
+    };
+
+    //Allocated for what is configured, setters grow the account as more is configured
+    let space = forward.space()?;
     invoke(&system_instruction::create_account(
         payer.key,
         forward_account.key,
-        Rent::get()?.minimum_balance(Forward::LEN),
-        Forward::LEN.try_into().unwrap(),
+        Rent::get()?.minimum_balance(space),
+        space.try_into().unwrap(),
         program_id,
     ),
            &[
@@ -56,13 +111,7 @@ fn create_forward_account<'a>(
            ],
     )?;
 
-    let forward = Forward::new(
-        destination_key.clone(),
-        instr.forward_pda,
-        instr.bump,
-    );
-
-    forward.serialize(&mut &mut forward_account.data.borrow_mut()[..])?;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
 
     Ok(())
 }
@@ -70,7 +119,8 @@ fn create_forward_account<'a>(
 fn validate(
     program_id: &Pubkey,
     forward_account: &AccountInfo,
-    destination_account: &AccountInfo,
+    destination_accounts: &[&AccountInfo],
+    quarantine_account: &AccountInfo,
     system_program: &AccountInfo,
     instr: &CreateForwardInstruction,
 ) -> ProgramResult {
@@ -82,15 +132,45 @@ fn validate(
                 forward_account.lamports() == 0 && Forward::try_from_slice(&forward_account.try_borrow_mut_data()?).is_err(),
                 ProgramError::from(ForwardError::ForwardAlreadyExists))?;
 
-    //TODO - is there a better way to do this?
-    assert_that("Destination is not an ATA",
-                SplToken2022Account::unpack(&destination_account.data.borrow()).is_err() && SplTokenAccount::unpack(&destination_account.data.borrow()).is_err(),
-                ProgramError::from(ForwardError::DestinationIsAnAta))?;
+    validate_recipients(destination_accounts, &instr.recipient_bps, instr.dust_recipient)?;
+
+    //The default key marks an unset quarantine on unversioned forwards, it is the system program and cannot receive a quarantine
+    assert_that("Quarantine is set", *quarantine_account.key != Pubkey::default(), ProgramError::from(ForwardError::InvalidQuarantine))?;
+    assert_not_an_ata("Quarantine is not an ATA", quarantine_account, ProgramError::from(ForwardError::QuarantineIsAnAta))?;
 
     let forward_pda_check =
-        Pubkey::create_program_address(&[Forward::FORWARD_SEED.as_ref(), forward_account.key.as_ref(), &[instr.bump]], program_id);
+        Pubkey::create_program_address(&[Forward::FORWARD_SEED, forward_account.key.as_ref(), &[instr.bump]], program_id);
 
     assert_that("Forward address is valid",
                 forward_pda_check.is_ok() && forward_pda_check.unwrap() == instr.forward_pda,
                 ProgramError::from(ForwardError::InvalidForwardAddress))
 }
+
+fn validate_recipients(
+    destination_accounts: &[&AccountInfo],
+    recipient_bps: &[u16],
+    dust_recipient: u8,
+) -> ProgramResult {
+
+    assert_that("Valid number of recipients",
+                !recipient_bps.is_empty() && recipient_bps.len() <= Recipient::MAX_RECIPIENTS && recipient_bps.len() == destination_accounts.len(),
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    assert_that("Recipient bps add up to 100%",
+                recipient_bps.iter().map(|bps| *bps as u32).sum::<u32>() == Recipient::TOTAL_BPS as u32,
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    assert_that("Dust recipient is a recipient",
+                (dust_recipient as usize) < recipient_bps.len(),
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    destination_accounts.iter().try_for_each(|destination_account|
+        assert_not_an_ata("Destination is not an ATA", destination_account, ProgramError::from(ForwardError::DestinationIsAnAta)))
+}
+
+//TODO - is there a better way to do this?
+pub fn assert_not_an_ata(requirement: &str, account: &AccountInfo, error: ProgramError) -> ProgramResult {
+    assert_that(requirement,
+                StateWithExtensions::<SplToken2022Account>::unpack(&account.data.borrow()).is_err() && SplTokenAccount::unpack(&account.data.borrow()).is_err(),
+                error)
+}
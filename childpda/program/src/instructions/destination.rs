@@ -0,0 +1,88 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::create::assert_not_an_ata;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::PendingDestination;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct UpdateDestinationInstruction {
+    index: u8,
+}
+
+/**
+ * Propose a new destination for one of the forward recipients, the new destination must accept it before it takes effect
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *  - accounts[4] The proposed destination account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn update_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: UpdateDestinationInstruction,
+) -> ProgramResult {
+
+    msg!("Update destination instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let new_destination = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+    assert_that("Recipient index is valid", (instr.index as usize) < forward.recipients.len(), ProgramError::from(ForwardError::InvalidSplit))?;
+    assert_not_an_ata("Destination is not an ATA", new_destination, ProgramError::from(ForwardError::DestinationIsAnAta))?;
+
+    forward.pending_destination = Some(PendingDestination { index: instr.index, destination: *new_destination.key });
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
+
+/**
+ * Accept the proposed destination, which replaces the destination of the recipient it was proposed for
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The proposed destination account (signer)
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn accept_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Accept destination instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let new_destination = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    assert_that("Destination is signer", new_destination.is_signer, ProgramError::MissingRequiredSignature)?;
+    let pending_destination = forward.pending_destination.take()
+        .filter(|pending_destination| pending_destination.destination == *new_destination.key)
+        .ok_or(ProgramError::from(ForwardError::InvalidDestination))?;
+
+    let recipient = forward.recipients.get_mut(pending_destination.index as usize).ok_or(ForwardError::InvalidSplit)?;
+    recipient.destination = pending_destination.destination;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
+
+    Ok(())
+}
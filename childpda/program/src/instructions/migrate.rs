@@ -0,0 +1,101 @@
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::Forward;
+
+/**
+ * Migrate a forward of an older version to the current version, reallocating it to the space it needs
+ * The payer tops up the rent for the added space, so deposits in the forward are not used for rent
+ * A forward already at the current version is left unchanged
+ * The stats of a migrated forward are counted from the migration, its created at is left as 0
+ * An unversioned forward has no authority, its destination must sign the migration and becomes the forward authority
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The payer account (signer)
+ *  - accounts[2] The system program account
+ *  - accounts[3] The destination account (signer) of an unversioned forward, which becomes its authority
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn migrate_forward(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Migrate forward instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    assert_that("System program is correct", system_account.key == &solana_program::system_program::id(), ProgramError::IncorrectProgramId)?;
+    assert_that("Payer is signer", payer.is_signer, ProgramError::MissingRequiredSignature)?;
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    if forward.version == Forward::VERSION {
+        msg!("Forward is already at version {}", Forward::VERSION);
+        return Ok(());
+    }
+
+    if forward.version == 0 {
+        let destination = next_account_info(accounts_iter)?;
+        assert_that("Destination is signer", destination.is_signer, ProgramError::MissingRequiredSignature)?;
+        assert_that("Destination is the forward destination", forward.recipients.iter().any(|recipient| recipient.destination == *destination.key), ProgramError::from(ForwardError::InvalidDestination))?;
+        forward.authority = *destination.key;
+    }
+
+    forward.version = Forward::VERSION;
+    resize_and_pack(&forward, forward_account, payer, system_account)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::account_info::AccountInfo;
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::ForwardError;
+    use crate::instructions::migrate::migrate_forward;
+    use crate::state::ForwardV0;
+
+    fn migrate_v0(destination: Pubkey, signer: Option<(Pubkey, bool)>) -> Result<(), ProgramError> {
+        let program_id = Pubkey::new_unique();
+        let (forward_key, payer_key, system_key) = (Pubkey::new_unique(), Pubkey::new_unique(), solana_program::system_program::id());
+        let (mut forward_lamports, mut payer_lamports, mut system_lamports, mut signer_lamports) = (1_000_000, 1_000_000, 1, 1_000_000);
+        let mut forward_data = borsh::to_vec(&ForwardV0 { destination, forward_pda: Pubkey::new_unique(), bump: 254 }).unwrap();
+        let (mut payer_data, mut system_data, mut signer_data) = (vec![], vec![], vec![]);
+        let (signer_key, signer_is_signer) = signer.unwrap_or_default();
+
+        let mut accounts = vec![
+            AccountInfo::new(&forward_key, false, true, &mut forward_lamports, &mut forward_data, &program_id, false, 0),
+            AccountInfo::new(&payer_key, true, true, &mut payer_lamports, &mut payer_data, &system_key, false, 0),
+            AccountInfo::new(&system_key, false, false, &mut system_lamports, &mut system_data, &system_key, true, 0),
+        ];
+        if signer.is_some() {
+            accounts.push(AccountInfo::new(&signer_key, signer_is_signer, false, &mut signer_lamports, &mut signer_data, &system_key, false, 0));
+        }
+        migrate_forward(&program_id, &accounts)
+    }
+
+    #[test]
+    fn does_not_migrate_an_unversioned_forward_without_its_destination() {
+        assert_eq!(migrate_v0(Pubkey::new_unique(), None).unwrap_err(), ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn does_not_migrate_an_unversioned_forward_unsigned_by_its_destination() {
+        let destination = Pubkey::new_unique();
+        assert_eq!(migrate_v0(destination, Some((destination, false))).unwrap_err(), ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn does_not_migrate_an_unversioned_forward_signed_by_another_account() {
+        assert_eq!(migrate_v0(Pubkey::new_unique(), Some((Pubkey::new_unique(), true))).unwrap_err(), ProgramError::from(ForwardError::InvalidDestination));
+    }
+}
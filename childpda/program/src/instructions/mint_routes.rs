@@ -0,0 +1,66 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::create::assert_not_an_ata;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::MintRoute;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetMintRoutesInstruction {
+    mint_routes: Vec<MintRoute>,
+}
+
+/**
+ * Set the mints that are forwarded to an override destination instead of being split across the recipients
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *  - Followed by the destination account of each mint route, in order
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_mint_routes(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetMintRoutesInstruction,
+) -> ProgramResult {
+
+    msg!("Set mint routes instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    assert_that("Valid number of mint routes",
+                instr.mint_routes.len() <= MintRoute::MAX_MINT_ROUTES && accounts.len() == 2 + instr.mint_routes.len(),
+                ProgramError::from(ForwardError::InvalidMintRoute))?;
+
+    assert_that("Mint routes are unique",
+                instr.mint_routes.iter().enumerate().all(|(i, mint_route)|
+                    instr.mint_routes[..i].iter().all(|other| other.mint != mint_route.mint)),
+                ProgramError::from(ForwardError::InvalidMintRoute))?;
+
+    for (mint_route, destination_account) in instr.mint_routes.iter().zip(accounts_iter) {
+        assert_that("Route destination is valid", *destination_account.key == mint_route.destination, ProgramError::from(ForwardError::InvalidMintRoute))?;
+        assert_not_an_ata("Route destination is not an ATA", destination_account, ProgramError::from(ForwardError::DestinationIsAnAta))?;
+    }
+
+    forward.mint_routes = instr.mint_routes;
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::validate_and_get_forward;
+use crate::state::NotBefore;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetNotBeforeInstruction {
+    not_before: Option<NotBefore>,
+}
+
+/**
+ * Shorten the time lock of the forward, the time lock can be removed or brought forward but never extended
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_not_before(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetNotBeforeInstruction,
+) -> ProgramResult {
+
+    msg!("Set not before instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    let shortened = match (&forward.not_before, &instr.not_before) {
+        (_, None) => true,
+        (Some(not_before), Some(new_not_before)) => new_not_before.is_no_later_than(not_before),
+        (None, Some(_)) => false,
+    };
+    assert_that("Time lock is only shortened", shortened, ProgramError::from(ForwardError::InvalidTimeLock))?;
+
+    forward.not_before = instr.not_before;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
+
+    Ok(())
+}
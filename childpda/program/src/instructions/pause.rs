@@ -0,0 +1,59 @@
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::validate_and_get_forward;
+
+/**
+ * Pause the forward, execute and close fail until the forward is unpaused, it can still be quarantined, deposits are still received
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn pause(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Pause instruction");
+    set_paused(program_id, accounts, true)
+}
+
+/**
+ * Unpause the forward, so it can be executed again
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn unpause(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Unpause instruction");
+    set_paused(program_id, accounts, false)
+}
+
+fn set_paused(program_id: &Pubkey, accounts: &[AccountInfo], paused: bool) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    forward.paused = paused;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_token_2022::check_system_program_account;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{forward_sol, maybe_forward_tokens, validate_and_get_forward};
+use crate::state::Recipient;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct QuarantineForwardInstruction {
+    quarantine_sol: bool
+}
+
+/**
+ * Quarantine the forward, moving sol and the selected tokens to the quarantine address instead of the recipients
+ * The time lock, vesting and rate limit of the forward do not apply, everything it holds is quarantined
+ * A paused forward can still be quarantined, as quarantine is the escape hatch during an incident
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward pda
+ *  - accounts[2] The quarantine account
+ *  - accounts[3] The system account
+ *  - accounts[4] The forward authority (signer)
+ *  - If tokens are to be quarantined, the following accounts are required
+ *      - accounts[5] The signer account (will pay for the quarantine ata to be created if it does not exist)
+ *      - accounts[6] The token program account
+ *      - accounts[7] The associated token program account
+ *
+ *      - Followed by the following 3 accounts for each mint/token to quarantine:
+ *      - accounts[8] The mint account
+ *      - The token program of the mint, only if the mint is owned by the other token program
+ *      - accounts[9] The forward ATA account
+ *      - accounts[10] The quarantine ATA account
+ *      - A mint with a transfer hook is then followed by the hook program, its extra account metas account and each extra account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn quarantine(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: QuarantineForwardInstruction,
+) -> ProgramResult {
+
+    msg!("Quarantine forward instruction, accounts {}", accounts.len());
+    assert_that("Valid number of accounts",
+                accounts.len() == 5 || accounts.len() >= 11,
+                ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
+
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let forward_pda = next_account_info(accounts_iter)?;
+    let quarantine_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+
+    check_system_program_account(system_program.key)?;
+    let forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+    assert_that("Quarantine is initialised", forward.quarantine != Pubkey::default(), ProgramError::from(ForwardError::QuarantineNotInitialised))?;
+    assert_that("Quarantine is valid", *quarantine_account.key == forward.quarantine, ProgramError::from(ForwardError::InvalidQuarantine))?;
+    assert_that("Forward pda belongs to this account", &forward.forward_pda == forward_pda.key, ProgramError::from(ForwardError::InvalidForwardAddress))?;
+
+    let quarantine_accounts = [quarantine_account];
+    let quarantine_recipients = [Recipient::new(forward.quarantine, Recipient::TOTAL_BPS)];
+
+    maybe_forward_tokens(&forward, forward_account, forward_pda, &quarantine_accounts, &quarantine_recipients, 0, false, system_program, accounts_iter.next(), None, false, None, None, None, None, accounts_iter)
+        .and_then(|_|
+        {
+            if instr.quarantine_sol {
+                return forward_sol(&forward, forward_account, forward_pda, &quarantine_accounts, &quarantine_recipients, 0, 0, None, None, None, None)
+            }
+            Ok(())
+        })
+}
@@ -0,0 +1,69 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::{MintCap, RateLimit};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetRateLimitInstruction {
+    window: i64,
+    lamport_cap: Option<u64>,
+    mint_caps: Vec<MintCap>,
+}
+
+/**
+ * Set the most sol and tokens of each capped mint the forward moves in a window of the given number of seconds
+ * Execute forwards up to what is left of the caps in the current window, the rest is left for a later window
+ * Setting no lamport cap and no mint caps removes the rate limit, setting the rate limit starts a new window
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_rate_limit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetRateLimitInstruction,
+) -> ProgramResult {
+
+    msg!("Set rate limit instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    forward.rate_limit = match (instr.lamport_cap, instr.mint_caps.is_empty()) {
+        (None, true) => None,
+        _ => {
+            assert_that("Window is positive", instr.window > 0, ProgramError::from(ForwardError::InvalidRateLimit))?;
+            assert_that("Valid number of mint caps",
+                        instr.mint_caps.len() <= MintCap::MAX_MINT_CAPS,
+                        ProgramError::from(ForwardError::InvalidRateLimit))?;
+            assert_that("Mint caps are unique",
+                        instr.mint_caps.iter().enumerate().all(|(i, mint_cap)|
+                            instr.mint_caps[..i].iter().all(|other| other.mint != mint_cap.mint)),
+                        ProgramError::from(ForwardError::InvalidRateLimit))?;
+            Some(RateLimit::new(instr.window, instr.lamport_cap, instr.mint_caps, &Clock::get()?))
+        }
+    };
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::Vesting;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetVestingInstruction {
+    start: i64,
+    end: i64,
+}
+
+/**
+ * Set the schedule the forward releases its sol and tokens over, linearly between the start and end unix timestamps
+ * Once set, the schedule can only be brought forward, what has already been released is kept
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_vesting(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetVestingInstruction,
+) -> ProgramResult {
+
+    msg!("Set vesting instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    assert_that("Vesting starts before it ends", instr.start < instr.end, ProgramError::from(ForwardError::InvalidVesting))?;
+    let vesting = match forward.vesting {
+        None => Vesting::new(instr.start, instr.end),
+        Some(vesting) => {
+            assert_that("Vesting is only brought forward",
+                        instr.start <= vesting.start && instr.end <= vesting.end,
+                        ProgramError::from(ForwardError::InvalidVesting))?;
+            Vesting { start: instr.start, end: instr.end, ..vesting }
+        }
+    };
+
+    forward.vesting = Some(vesting);
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
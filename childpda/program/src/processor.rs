@@ -1,13 +1,49 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::instructions::create::{create, CreateForwardInstruction};
-use crate::instructions::execute::{execute, ExecuteForwardInstruction};
+use crate::instructions::authority::{set_authority, SetAuthorityInstruction};
+use crate::instructions::bounty::{set_bounty, SetBountyInstruction};
+use crate::instructions::close::close;
+use crate::instructions::config::{init_config, split_config, update_config, InitConfigInstruction, UpdateConfigInstruction};
+use crate::instructions::create::{create, CreateForwardInstruction, CreateForwardV1Instruction};
+use crate::instructions::destination::{accept_destination, update_destination, UpdateDestinationInstruction};
+use crate::instructions::execute::{execute, ExecuteForwardInstruction, ExecuteForwardV1Instruction};
+use crate::instructions::harvest::harvest_withheld_fees;
+use crate::instructions::migrate::migrate_forward;
+use crate::instructions::mint_policy::{set_mint_policy, SetMintPolicyInstruction};
+use crate::instructions::mint_routes::{set_mint_routes, SetMintRoutesInstruction};
+use crate::instructions::not_before::{set_not_before, SetNotBeforeInstruction};
+use crate::instructions::pause::{pause, unpause};
+use crate::instructions::quarantine::{quarantine, QuarantineForwardInstruction};
+use crate::instructions::rate_limit::{set_rate_limit, SetRateLimitInstruction};
+use crate::instructions::thresholds::{set_thresholds, SetThresholdsInstruction};
+use crate::instructions::vesting::{set_vesting, SetVestingInstruction};
+use crate::state::Config;
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum ForwardInstruction {
-    CreateForward(CreateForwardInstruction),
-    Execute(ExecuteForwardInstruction)
+    CreateForward(CreateForwardV1Instruction),
+    Execute(ExecuteForwardV1Instruction),
+    Quarantine(QuarantineForwardInstruction),
+    SetAuthority(SetAuthorityInstruction),
+    CloseForward,
+    UpdateDestination(UpdateDestinationInstruction),
+    AcceptDestination,
+    SetThresholds(SetThresholdsInstruction),
+    SetBounty(SetBountyInstruction),
+    SetMintPolicy(SetMintPolicyInstruction),
+    SetMintRoutes(SetMintRoutesInstruction),
+    HarvestWithheldFees,
+    ExecuteV2(ExecuteForwardInstruction),
+    CreateForwardV2(CreateForwardInstruction),
+    SetNotBefore(SetNotBeforeInstruction),
+    SetVesting(SetVestingInstruction),
+    SetRateLimit(SetRateLimitInstruction),
+    Pause,
+    Unpause,
+    InitConfig(InitConfigInstruction),
+    UpdateConfig(UpdateConfigInstruction),
+    MigrateForward,
 }
 
 pub fn process_instruction(
@@ -19,7 +55,43 @@ pub fn process_instruction(
     let instruction = ForwardInstruction::try_from_slice(instruction_data)?;
 
     match instruction {
-        ForwardInstruction::CreateForward(instr) => { create(program_id, accounts, instr)}
-        ForwardInstruction::Execute(instr) => { execute(program_id, accounts, instr)}
+        ForwardInstruction::InitConfig(instr) => { init_config(program_id, accounts, instr)}
+        ForwardInstruction::UpdateConfig(instr) => { update_config(program_id, accounts, instr)}
+        instruction => {
+            let (config, accounts) = split_config(program_id, accounts)?;
+            process_forward_instruction(program_id, accounts, instruction, &config)
+        }
+    }
+}
+
+fn process_forward_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: ForwardInstruction,
+    config: &Config,
+) -> ProgramResult {
+
+    match instruction {
+        ForwardInstruction::CreateForward(instr) => { create(program_id, accounts, instr.into())}
+        ForwardInstruction::Execute(instr) => { execute(program_id, accounts, instr.into(), config)}
+        ForwardInstruction::Quarantine(instr) => { quarantine(program_id, accounts, instr)}
+        ForwardInstruction::SetAuthority(instr) => { set_authority(program_id, accounts, instr)}
+        ForwardInstruction::CloseForward => { close(program_id, accounts)}
+        ForwardInstruction::UpdateDestination(instr) => { update_destination(program_id, accounts, instr)}
+        ForwardInstruction::AcceptDestination => { accept_destination(program_id, accounts)}
+        ForwardInstruction::SetThresholds(instr) => { set_thresholds(program_id, accounts, instr)}
+        ForwardInstruction::SetBounty(instr) => { set_bounty(program_id, accounts, instr)}
+        ForwardInstruction::SetMintPolicy(instr) => { set_mint_policy(program_id, accounts, instr)}
+        ForwardInstruction::SetMintRoutes(instr) => { set_mint_routes(program_id, accounts, instr)}
+        ForwardInstruction::HarvestWithheldFees => { harvest_withheld_fees(program_id, accounts)}
+        ForwardInstruction::ExecuteV2(instr) => { execute(program_id, accounts, instr, config)}
+        ForwardInstruction::CreateForwardV2(instr) => { create(program_id, accounts, instr)}
+        ForwardInstruction::SetNotBefore(instr) => { set_not_before(program_id, accounts, instr)}
+        ForwardInstruction::SetVesting(instr) => { set_vesting(program_id, accounts, instr)}
+        ForwardInstruction::SetRateLimit(instr) => { set_rate_limit(program_id, accounts, instr)}
+        ForwardInstruction::Pause => { pause(program_id, accounts)}
+        ForwardInstruction::Unpause => { unpause(program_id, accounts)}
+        ForwardInstruction::MigrateForward => { migrate_forward(program_id, accounts)}
+        ForwardInstruction::InitConfig(_) | ForwardInstruction::UpdateConfig(_) => { Err(ProgramError::InvalidInstructionData)}
     }
 }
\ No newline at end of file
@@ -1,3 +1,25 @@
+pub mod account_type;
+pub mod bounty;
+pub mod config;
 pub mod forward;
+pub mod mint_policy;
+pub mod mint_route;
+pub mod not_before;
+pub mod rate_limit;
+pub mod recipient;
+pub mod stats;
+pub mod threshold;
+pub mod vesting;
 
-pub use forward::*;
\ No newline at end of file
+pub use account_type::*;
+pub use bounty::*;
+pub use config::*;
+pub use forward::*;
+pub use mint_policy::*;
+pub use mint_route::*;
+pub use not_before::*;
+pub use rate_limit::*;
+pub use recipient::*;
+pub use stats::*;
+pub use threshold::*;
+pub use vesting::*;
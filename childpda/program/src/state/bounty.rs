@@ -0,0 +1,27 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::Recipient;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum Bounty {
+    None,
+    Lamports(u64),
+    Bps(u16),
+}
+
+impl Bounty {
+
+    pub const LEN: usize = 1 + 8; //variant + largest value
+
+    /**
+     * The bounty owed to the signer out of the available sol, never more than the available sol
+     */
+    pub fn amount(&self, available_sol: u64) -> u64 {
+        let bounty = match self {
+            Bounty::None => 0,
+            Bounty::Lamports(lamports) => *lamports,
+            Bounty::Bps(bps) => (available_sol as u128 * *bps as u128 / Recipient::TOTAL_BPS as u128) as u64,
+        };
+        bounty.min(available_sol)
+    }
+}
@@ -0,0 +1,45 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::AccountType;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct Config {
+    pub account_type: AccountType,
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub emergency_stop: bool,
+    pub max_mints_per_execute: u8,
+}
+
+impl Config {
+
+    pub const CONFIG_SEED: &'static[u8] = b"config";
+    pub const LEN: usize = AccountType::LEN + 1 + 32 + 1 + 1; //account type + bump + admin + emergency stop + max mints per execute
+    pub const DEFAULT_MAX_MINTS_PER_EXECUTE: u8 = 10;
+
+    pub fn new(bump: u8, admin: Pubkey, max_mints_per_execute: u8) -> Self {
+        Config {
+            account_type: AccountType::Config,
+            bump,
+            admin,
+            emergency_stop: false,
+            max_mints_per_execute,
+        }
+    }
+
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        AccountType::Config.validate(data)?;
+        Ok(Self::deserialize(&mut &data[..])?)
+    }
+}
+
+/**
+ * The config used until the config account is initialised
+ */
+impl Default for Config {
+    fn default() -> Self {
+        Config::new(0, Pubkey::default(), Self::DEFAULT_MAX_MINTS_PER_EXECUTE)
+    }
+}
@@ -0,0 +1,30 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum MintPolicy {
+    None,
+    Allow(Vec<Pubkey>),
+    Deny(Vec<Pubkey>),
+}
+
+impl MintPolicy {
+
+    pub const MAX_MINTS: usize = 5;
+    pub const LEN: usize = 1 + (4 + MintPolicy::MAX_MINTS * 32); //variant + mints
+
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        match self {
+            MintPolicy::None => true,
+            MintPolicy::Allow(mints) => mints.contains(mint),
+            MintPolicy::Deny(mints) => !mints.contains(mint),
+        }
+    }
+
+    pub fn mints(&self) -> &[Pubkey] {
+        match self {
+            MintPolicy::None => &[],
+            MintPolicy::Allow(mints) | MintPolicy::Deny(mints) => mints,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::ForwardError;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MintCap {
+    pub mint: Pubkey,
+    pub cap: u64,
+}
+
+impl MintCap {
+
+    pub const LEN: usize = 32 + 8; //mint + cap
+    pub const MAX_MINT_CAPS: usize = 5;
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct RateLimit {
+    pub window: i64,
+    pub lamport_cap: Option<u64>,
+    pub mint_caps: Vec<MintCap>,
+    pub window_start: i64,
+    pub used_lamports: u64,
+    pub used_mints: Vec<u64>,
+}
+
+impl RateLimit {
+
+    //window + lamport cap + mint caps + window start + used lamports + used mints
+    pub const LEN: usize = 8 + (1 + 8) + (4 + MintCap::MAX_MINT_CAPS * MintCap::LEN) + 8 + 8 + (4 + MintCap::MAX_MINT_CAPS * 8);
+
+    pub fn new(window: i64, lamport_cap: Option<u64>, mint_caps: Vec<MintCap>, clock: &Clock) -> Self {
+        let used_mints = vec![0; mint_caps.len()];
+        RateLimit {
+            window,
+            lamport_cap,
+            mint_caps,
+            window_start: clock.unix_timestamp,
+            used_lamports: 0,
+            used_mints,
+        }
+    }
+
+    /**
+     * Start a new window once the current window has elapsed, the usage of the new window starts from nothing
+     */
+    pub fn roll(&mut self, clock: &Clock) {
+        if clock.unix_timestamp >= self.window_start.saturating_add(self.window) {
+            self.window_start = clock.unix_timestamp;
+            self.used_lamports = 0;
+            self.used_mints.iter_mut().for_each(|used| *used = 0);
+        }
+    }
+
+    pub fn lamport_allowance(&self) -> u64 {
+        self.lamport_cap.map_or(u64::MAX, |cap| cap.saturating_sub(self.used_lamports))
+    }
+
+    /**
+     * The tokens of the mint that can still be forwarded in this window, mints without a cap are not limited
+     */
+    pub fn mint_allowance(&self, mint: &Pubkey) -> u64 {
+        self.mint_caps.iter().zip(&self.used_mints)
+            .find(|(mint_cap, _)| mint_cap.mint == *mint)
+            .map_or(u64::MAX, |(mint_cap, used)| mint_cap.cap.saturating_sub(*used))
+    }
+
+    pub fn use_lamports(&mut self, amount: u64) -> ProgramResult {
+        self.used_lamports = self.used_lamports.checked_add(amount).ok_or(ForwardError::OverflowError)?;
+        Ok(())
+    }
+
+    pub fn use_mint(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
+        if let Some((_, used)) = self.mint_caps.iter().zip(self.used_mints.iter_mut()).find(|(mint_cap, _)| mint_cap.mint == *mint) {
+            *used = used.checked_add(amount).ok_or(ForwardError::OverflowError)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::ForwardError;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct Recipient {
+    pub destination: Pubkey,
+    pub bps: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct PendingDestination {
+    pub index: u8,
+    pub destination: Pubkey,
+}
+
+impl Recipient {
+
+    pub const LEN: usize = 32 + 2; //destination + bps
+    pub const MAX_RECIPIENTS: usize = 5;
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    pub fn new(destination: Pubkey, bps: u16) -> Self {
+        Recipient {
+            destination,
+            bps
+        }
+    }
+
+    /**
+     * Splits the amount across the recipients by their bps, any rounding dust goes to the dust recipient
+     */
+    pub fn split(amount: u64, recipients: &[Recipient], dust_recipient: u8) -> Result<Vec<u64>, ProgramError> {
+        let mut shares = recipients.iter()
+            .map(|recipient| (amount as u128 * recipient.bps as u128 / Recipient::TOTAL_BPS as u128) as u64)
+            .collect::<Vec<u64>>();
+
+        let dust = amount.checked_sub(shares.iter().sum()).ok_or(ForwardError::UnderflowError)?;
+        let dust_share = shares.get_mut(dust_recipient as usize).ok_or(ForwardError::InvalidSplit)?;
+        *dust_share = dust_share.checked_add(dust).ok_or(ForwardError::OverflowError)?;
+
+        Ok(shares)
+    }
+}
+
+impl PendingDestination {
+
+    pub const LEN: usize = 1 + 32; //index + destination
+}
@@ -0,0 +1,65 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MintTotal {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl MintTotal {
+
+    pub const LEN: usize = 32 + 8; //mint + amount
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Default)]
+pub struct Stats {
+    pub created_at: i64,
+    pub last_executed_at: i64,
+    pub execution_count: u64,
+    pub lamports_forwarded: u64,
+    pub mint_totals: Vec<MintTotal>,
+}
+
+impl Stats {
+
+    pub const MAX_MINT_TOTALS: usize = 5;
+    //created at + last executed at + execution count + lamports forwarded + mint totals
+    pub const LEN: usize = 8 + 8 + 8 + 8 + (4 + Self::MAX_MINT_TOTALS * MintTotal::LEN);
+
+    pub fn new(created_at: i64) -> Self {
+        Stats {
+            created_at,
+            ..Stats::default()
+        }
+    }
+
+    pub fn mint_total(&self, mint: &Pubkey) -> u64 {
+        self.mint_totals.iter()
+            .find(|mint_total| mint_total.mint == *mint)
+            .map_or(0, |mint_total| mint_total.amount)
+    }
+
+    pub fn record_execute(&mut self, clock: &Clock) {
+        self.last_executed_at = clock.unix_timestamp;
+        self.execution_count = self.execution_count.saturating_add(1);
+    }
+
+    pub fn record_lamports(&mut self, amount: u64) {
+        self.lamports_forwarded = self.lamports_forwarded.saturating_add(amount);
+    }
+
+    /**
+     * Add the tokens forwarded to the total for a mint, only the first MAX_MINT_TOTALS mints forwarded are totalled
+     */
+    pub fn record_mint(&mut self, mint: &Pubkey, amount: u64) {
+        if let Some(mint_total) = self.mint_totals.iter_mut().find(|mint_total| mint_total.mint == *mint) {
+            mint_total.amount = mint_total.amount.saturating_add(amount);
+            return;
+        }
+        if self.mint_totals.len() < Self::MAX_MINT_TOTALS {
+            self.mint_totals.push(MintTotal { mint: *mint, amount });
+        }
+    }
+}
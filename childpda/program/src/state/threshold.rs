@@ -0,0 +1,21 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MintThreshold {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl MintThreshold {
+
+    pub const LEN: usize = 32 + 8; //mint + amount
+    pub const MAX_MINT_THRESHOLDS: usize = 5;
+
+    pub fn new(mint: Pubkey, amount: u64) -> Self {
+        MintThreshold {
+            mint,
+            amount
+        }
+    }
+}
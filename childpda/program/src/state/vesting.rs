@@ -0,0 +1,121 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::ForwardError;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MintReleased {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl MintReleased {
+
+    pub const LEN: usize = 32 + 8; //mint + amount
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct Vesting {
+    pub start: i64,
+    pub end: i64,
+    pub released_lamports: u64,
+    pub released_mints: Vec<MintReleased>,
+}
+
+impl Vesting {
+
+    pub const MAX_RELEASED_MINTS: usize = 5;
+    //start + end + released lamports + released mints
+    pub const LEN: usize = 8 + 8 + 8 + (4 + Self::MAX_RELEASED_MINTS * MintReleased::LEN);
+
+    pub fn new(start: i64, end: i64) -> Self {
+        Vesting {
+            start,
+            end,
+            released_lamports: 0,
+            released_mints: Vec::new(),
+        }
+    }
+
+    pub fn has_ended(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.end
+    }
+
+    /**
+     * The part of the balance that has vested and not yet been released, everything ever deposited vests
+     * linearly between the start and the end, so the vested total is the balance plus what has been released
+     */
+    pub fn releasable(&self, balance: u64, released: u64, clock: &Clock) -> Result<u64, ProgramError> {
+        let total = balance.checked_add(released).ok_or(ForwardError::OverflowError)?;
+        let vested = match clock.unix_timestamp {
+            now if now <= self.start => 0,
+            now if now >= self.end => total,
+            now => {
+                let elapsed = now.abs_diff(self.start) as u128;
+                let duration = self.end.abs_diff(self.start) as u128;
+                u64::try_from(total as u128 * elapsed / duration).map_err(|_| ForwardError::OverflowError)?
+            }
+        };
+        Ok(vested.saturating_sub(released).min(balance))
+    }
+
+    pub fn released_mint(&self, mint: &Pubkey) -> u64 {
+        self.released_mints.iter()
+            .find(|released| released.mint == *mint)
+            .map_or(0, |released| released.amount)
+    }
+
+    pub fn release_lamports(&mut self, amount: u64) -> ProgramResult {
+        self.released_lamports = self.released_lamports.checked_add(amount).ok_or(ForwardError::OverflowError)?;
+        Ok(())
+    }
+
+    /**
+     * Whether the tokens released for a mint can be recorded, a vesting forward tracks at most MAX_RELEASED_MINTS mints
+     */
+    pub fn can_release_mint(&self, mint: &Pubkey) -> bool {
+        self.released_mints.len() < Self::MAX_RELEASED_MINTS || self.released_mints.iter().any(|released| released.mint == *mint)
+    }
+
+    /**
+     * Record the tokens released for a mint, a vesting forward can release at most MAX_RELEASED_MINTS mints
+     */
+    pub fn release_mint(&mut self, mint: &Pubkey, amount: u64) -> ProgramResult {
+        if let Some(released) = self.released_mints.iter_mut().find(|released| released.mint == *mint) {
+            released.amount = released.amount.checked_add(amount).ok_or(ForwardError::OverflowError)?;
+            return Ok(());
+        }
+        if self.released_mints.len() >= Self::MAX_RELEASED_MINTS {
+            return Err(ForwardError::VestingMintsFull.into());
+        }
+        self.released_mints.push(MintReleased { mint: *mint, amount });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::ForwardError;
+    use crate::state::Vesting;
+
+    #[test]
+    fn tracks_at_most_the_max_released_mints() {
+        let mut vesting = Vesting::new(0, 100);
+        let mints = (0..Vesting::MAX_RELEASED_MINTS).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        mints.iter().for_each(|mint| vesting.release_mint(mint, 10).unwrap());
+
+        let untracked = Pubkey::new_unique();
+        assert!(vesting.can_release_mint(&mints[0]));
+        assert!(!vesting.can_release_mint(&untracked));
+        assert_eq!(vesting.release_mint(&untracked, 10).unwrap_err(), ProgramError::from(ForwardError::VestingMintsFull));
+
+        vesting.release_mint(&mints[0], 5).unwrap();
+        assert_eq!(vesting.released_mint(&mints[0]), 15);
+    }
+}
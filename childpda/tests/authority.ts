@@ -0,0 +1,90 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram} from "@solana/web3.js";
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, setAuthority} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Forward} from "./classes/classes";
+
+describe("authority instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const executeRequiresAuthority = true;
+    const forwardSol = true;
+
+    let destination, quarantine, authority, forwardAccount, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        authority = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection, SystemProgram.programId, executeRequiresAuthority);
+    });
+
+    it("Should not execute without the authority when the authority is required", async () => {
+        await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not execute with a signer that is not the authority", async () => {
+        await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, Keypair.generate());
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should execute when signed by the authority", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        const forwardAmount = LAMPORTS_PER_SOL / 100;
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, authority);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const destinationBalanceAfter = await connection.getBalance(destination.publicKey);
+        expect(destinationBalanceAfter - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should allow the authority to make the forward permissionless", async () => {
+        const newAuthority = Keypair.generate();
+        try {
+            await setAuthority(forwardAccount.publicKey, authority, newAuthority.publicKey, !executeRequiresAuthority, program, payer, connection);
+            await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(newAuthority.publicKey);
+        expect(fwd.executeRequiresAuthority, "execute requires authority").to.equal(0);
+    });
+
+    it("Should not set the authority without the current authority", async () => {
+        const bogusAuthority = Keypair.generate();
+        try {
+            await setAuthority(forwardAccount.publicKey, bogusAuthority, bogusAuthority.publicKey, !executeRequiresAuthority, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have set the authority")
+    });
+});
@@ -0,0 +1,105 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, setBounty} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Bounty} from "./classes/classes";
+const forwardSol = true;
+
+describe("bounty instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+
+    let destination, quarantine, forwardAccount, authority, keeper, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        keeper = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        await initialiseAccountWithMinimumBalance(keeper.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+    });
+
+    it("Should pay a fixed bounty to the signer", async () => {
+        const bounty = 5000;
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        const keeperBalanceBefore = await connection.getBalance(keeper.publicKey);
+        try {
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'lamports', lamports: bounty}), program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, keeper);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(keeper.publicKey) - keeperBalanceBefore, "keeper").to.equal(bounty);
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore, "destination").to.equal(forwardAmount - bounty);
+    });
+
+    it("Should pay a bps bounty to the signer", async () => {
+        const keeperBalanceBefore = await connection.getBalance(keeper.publicKey);
+        try {
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'bps', bps: 100}), program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, keeper);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(keeper.publicKey) - keeperBalanceBefore).to.equal(forwardAmount / 100);
+    });
+
+    it("Should cap the bounty at the forwarded sol, leaving the forward rent", async () => {
+        const forwardRent = await connection.getBalance(forwardAccount.publicKey);
+        const keeperBalanceBefore = await connection.getBalance(keeper.publicKey);
+        try {
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'lamports', lamports: LAMPORTS_PER_SOL}), program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, keeper);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(keeper.publicKey) - keeperBalanceBefore, "keeper").to.equal(forwardAmount);
+        expect(await connection.getBalance(forwardPda), "forward pda").to.equal(0);
+        expect(await connection.getBalance(forwardAccount.publicKey), "forward").to.equal(forwardRent);
+    });
+
+    it("Should not pay a bounty without a signer", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'lamports', lamports: 5000}), program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should not set a bps bounty over 100%", async () => {
+        try {
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'bps', bps: 10001}), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x11")
+            return;
+        }
+        expect.fail("Should not have set the bounty")
+    });
+
+    it("Should not set the bounty without the authority", async () => {
+        try {
+            await setBounty(forwardAccount.publicKey, Keypair.generate(), new Bounty({enum: 'bps', bps: 100}), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have set the bounty")
+    });
+});
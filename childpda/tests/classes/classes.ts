@@ -3,7 +3,27 @@ import * as borsh from "borsh";
 
 export enum ForwardInstructions {
     CreateForward,
-    Execute
+    Execute,
+    Quarantine,
+    SetAuthority,
+    CloseForward,
+    UpdateDestination,
+    AcceptDestination,
+    SetThresholds,
+    SetBounty,
+    SetMintPolicy,
+    SetMintRoutes,
+    HarvestWithheldFees,
+    ExecuteV2,
+    CreateForwardV2,
+    SetNotBefore,
+    SetVesting,
+    SetRateLimit,
+    Pause,
+    Unpause,
+    InitConfig,
+    UpdateConfig,
+    MigrateForward
 }
 
 class Assignable {
@@ -14,11 +34,45 @@ class Assignable {
     };
 }
 
+export class Recipient extends Assignable {}
+
+export class PendingDestination extends Assignable {}
+
+export class MintThreshold extends Assignable {}
+
+export class Bounty extends Assignable {}
+
+export class BountyNone extends Assignable {}
+
+export class MintPolicy extends Assignable {}
+
+export class MintPolicyNone extends Assignable {}
+
+export class MintRoute extends Assignable {}
+
+//unixTimestamp is serialised as a u64, the timestamps used are never negative
+export class NotBefore extends Assignable {}
+
+//start and end are serialised as u64, the timestamps used are never negative
+export class Vesting extends Assignable {}
+
+export class MintReleased extends Assignable {}
+
+//window and windowStart are serialised as u64, they are never negative
+export class RateLimit extends Assignable {}
+
+export class MintCap extends Assignable {}
+
+//createdAt and lastExecutedAt are serialised as u64, they are never negative
+export class Stats extends Assignable {}
+
+export class MintTotal extends Assignable {}
+
 export class Forward extends Assignable {
     toBuffer() { return Buffer.from(borsh.serialize(ForwardSchema, this)) }
 
     static fromBuffer(buffer: Buffer) {
-        return borsh.deserialize(ForwardSchema, Forward, buffer);
+        return borsh.deserializeUnchecked(ForwardSchema, Forward, buffer);
     };
 }
 
@@ -26,9 +80,139 @@ const ForwardSchema = new Map([
     [ Forward, {
         kind: 'struct',
         fields: [
-            ['destination', [32]],
+            ['accountType', 'u8'],
+            ['version', 'u8'],
             ['forwardPda', [32]],
-            ['bump', 'u8']
+            ['bump', 'u8'],
+            ['authority', [32]],
+            ['quarantine', [32]],
+            ['executeRequiresAuthority', 'u8'],
+            ['recipients', [Recipient]],
+            ['dustRecipient', 'u8'],
+            ['pendingDestination', {kind: 'option', type: PendingDestination}],
+            ['minLamports', 'u64'],
+            ['mintThresholds', [MintThreshold]],
+            ['bounty', Bounty],
+            ['mintPolicy', MintPolicy],
+            ['mintRoutes', [MintRoute]],
+            ['notBefore', {kind: 'option', type: NotBefore}],
+            ['vesting', {kind: 'option', type: Vesting}],
+            ['rateLimit', {kind: 'option', type: RateLimit}],
+            ['paused', 'u8'],
+            ['stats', Stats]
+        ],
+    }],
+    [ Recipient, {
+        kind: 'struct',
+        fields: [
+            ['destination', [32]],
+            ['bps', 'u16'],
+        ],
+    }],
+    [ PendingDestination, {
+        kind: 'struct',
+        fields: [
+            ['index', 'u8'],
+            ['destination', [32]],
+        ],
+    }],
+    [ MintThreshold, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['amount', 'u64'],
+        ],
+    }],
+    [ Bounty, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['none', BountyNone],
+            ['lamports', 'u64'],
+            ['bps', 'u16'],
+        ],
+    }],
+    [ BountyNone, {
+        kind: 'struct',
+        fields: [],
+    }],
+    [ MintPolicy, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['none', MintPolicyNone],
+            ['allow', [[32]]],
+            ['deny', [[32]]],
+        ],
+    }],
+    [ MintPolicyNone, {
+        kind: 'struct',
+        fields: [],
+    }],
+    [ MintRoute, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['destination', [32]],
+        ],
+    }],
+    [ NotBefore, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['unixTimestamp', 'u64'],
+            ['slot', 'u64'],
+        ],
+    }],
+    [ Vesting, {
+        kind: 'struct',
+        fields: [
+            ['start', 'u64'],
+            ['end', 'u64'],
+            ['releasedLamports', 'u64'],
+            ['releasedMints', [MintReleased]],
+        ],
+    }],
+    [ MintReleased, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['amount', 'u64'],
+        ],
+    }],
+    [ RateLimit, {
+        kind: 'struct',
+        fields: [
+            ['window', 'u64'],
+            ['lamportCap', {kind: 'option', type: 'u64'}],
+            ['mintCaps', [MintCap]],
+            ['windowStart', 'u64'],
+            ['usedLamports', 'u64'],
+            ['usedMints', ['u64']],
+        ],
+    }],
+    [ MintCap, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['cap', 'u64'],
+        ],
+    }],
+    [ Stats, {
+        kind: 'struct',
+        fields: [
+            ['createdAt', 'u64'],
+            ['lastExecutedAt', 'u64'],
+            ['executionCount', 'u64'],
+            ['lamportsForwarded', 'u64'],
+            ['mintTotals', [MintTotal]],
+        ],
+    }],
+    [ MintTotal, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['amount', 'u64'],
         ],
     }]
 ]);
@@ -48,6 +232,42 @@ const CreateForwardInstructionSchema = new Map([
             ['instruction', 'u8'],
             ['forwardPda', [32]],
             ['bump', 'u8'],
+            ['authority', [32]],
+            ['executeRequiresAuthority', 'u8'],
+            ['recipientBps', ['u16']],
+            ['dustRecipient', 'u8'],
+        ],
+    }]
+]);
+
+export class CreateForwardV2Instruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(CreateForwardV2InstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(CreateForwardV2InstructionSchema, CreateForwardV2Instruction, buffer);
+    };
+}
+
+const CreateForwardV2InstructionSchema = new Map([
+    [ CreateForwardV2Instruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['forwardPda', [32]],
+            ['bump', 'u8'],
+            ['authority', [32]],
+            ['executeRequiresAuthority', 'u8'],
+            ['recipientBps', ['u16']],
+            ['dustRecipient', 'u8'],
+            ['notBefore', {kind: 'option', type: NotBefore}],
+        ],
+    }],
+    [ NotBefore, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['unixTimestamp', 'u64'],
+            ['slot', 'u64'],
         ],
     }]
 ]);
@@ -70,3 +290,437 @@ const ExecuteForwardInstructionSchema = new Map([
     }]
 ]);
 
+
+export class ExecuteForwardV2Instruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(ExecuteForwardV2InstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(ExecuteForwardV2InstructionSchema, ExecuteForwardV2Instruction, buffer);
+    };
+}
+
+const ExecuteForwardV2InstructionSchema = new Map([
+    [ ExecuteForwardV2Instruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['forward_sol', 'u8'],
+            ['close_emptied_atas', 'u8'],
+            ['unwrap_native', 'u8'],
+            ['stake_accounts', 'u8']
+        ],
+    }]
+]);
+
+
+export class QuarantineForwardInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(QuarantineForwardInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(QuarantineForwardInstructionSchema, QuarantineForwardInstruction, buffer);
+    };
+}
+
+const QuarantineForwardInstructionSchema = new Map([
+    [ QuarantineForwardInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['quarantine_sol', 'u8']
+        ],
+    }]
+]);
+
+export class SetAuthorityInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetAuthorityInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetAuthorityInstructionSchema, SetAuthorityInstruction, buffer);
+    };
+}
+
+const SetAuthorityInstructionSchema = new Map([
+    [ SetAuthorityInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['executeRequiresAuthority', 'u8'],
+        ],
+    }]
+]);
+
+export class CloseForwardInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(CloseForwardInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(CloseForwardInstructionSchema, CloseForwardInstruction, buffer);
+    };
+}
+
+const CloseForwardInstructionSchema = new Map([
+    [ CloseForwardInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
+
+export class HarvestWithheldFeesInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(HarvestWithheldFeesInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(HarvestWithheldFeesInstructionSchema, HarvestWithheldFeesInstruction, buffer);
+    };
+}
+
+const HarvestWithheldFeesInstructionSchema = new Map([
+    [ HarvestWithheldFeesInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
+
+export class UpdateDestinationInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(UpdateDestinationInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(UpdateDestinationInstructionSchema, UpdateDestinationInstruction, buffer);
+    };
+}
+
+const UpdateDestinationInstructionSchema = new Map([
+    [ UpdateDestinationInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['index', 'u8'],
+        ],
+    }]
+]);
+
+export class AcceptDestinationInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(AcceptDestinationInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(AcceptDestinationInstructionSchema, AcceptDestinationInstruction, buffer);
+    };
+}
+
+const AcceptDestinationInstructionSchema = new Map([
+    [ AcceptDestinationInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
+
+export class SetThresholdsInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetThresholdsInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetThresholdsInstructionSchema, SetThresholdsInstruction, buffer);
+    };
+}
+
+const SetThresholdsInstructionSchema = new Map([
+    [ SetThresholdsInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['minLamports', 'u64'],
+            ['mintThresholds', [MintThreshold]],
+        ],
+    }],
+    [ MintThreshold, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['amount', 'u64'],
+        ],
+    }],
+    [ Bounty, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['none', BountyNone],
+            ['lamports', 'u64'],
+            ['bps', 'u16'],
+        ],
+    }],
+    [ BountyNone, {
+        kind: 'struct',
+        fields: [],
+    }]
+]);
+
+export class SetBountyInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetBountyInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetBountyInstructionSchema, SetBountyInstruction, buffer);
+    };
+}
+
+const SetBountyInstructionSchema = new Map([
+    [ SetBountyInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['bounty', Bounty],
+        ],
+    }],
+    [ Bounty, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['none', BountyNone],
+            ['lamports', 'u64'],
+            ['bps', 'u16'],
+        ],
+    }],
+    [ BountyNone, {
+        kind: 'struct',
+        fields: [],
+    }]
+]);
+
+export class SetMintPolicyInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetMintPolicyInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetMintPolicyInstructionSchema, SetMintPolicyInstruction, buffer);
+    };
+}
+
+const SetMintPolicyInstructionSchema = new Map([
+    [ SetMintPolicyInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['mintPolicy', MintPolicy],
+        ],
+    }],
+    [ MintPolicy, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['none', MintPolicyNone],
+            ['allow', [[32]]],
+            ['deny', [[32]]],
+        ],
+    }],
+    [ MintPolicyNone, {
+        kind: 'struct',
+        fields: [],
+    }]
+]);
+
+export class SetMintRoutesInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetMintRoutesInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetMintRoutesInstructionSchema, SetMintRoutesInstruction, buffer);
+    };
+}
+
+const SetMintRoutesInstructionSchema = new Map([
+    [ SetMintRoutesInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['mintRoutes', [MintRoute]],
+        ],
+    }],
+    [ MintRoute, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['destination', [32]],
+        ],
+    }]
+]);
+
+export class SetNotBeforeInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetNotBeforeInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetNotBeforeInstructionSchema, SetNotBeforeInstruction, buffer);
+    };
+}
+
+const SetNotBeforeInstructionSchema = new Map([
+    [ SetNotBeforeInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['notBefore', {kind: 'option', type: NotBefore}],
+        ],
+    }],
+    [ NotBefore, {
+        kind: 'enum',
+        field: 'enum',
+        values: [
+            ['unixTimestamp', 'u64'],
+            ['slot', 'u64'],
+        ],
+    }]
+]);
+
+export class SetVestingInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetVestingInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetVestingInstructionSchema, SetVestingInstruction, buffer);
+    };
+}
+
+const SetVestingInstructionSchema = new Map([
+    [ SetVestingInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['start', 'u64'],
+            ['end', 'u64'],
+        ],
+    }]
+]);
+
+export class SetRateLimitInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(SetRateLimitInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(SetRateLimitInstructionSchema, SetRateLimitInstruction, buffer);
+    };
+}
+
+const SetRateLimitInstructionSchema = new Map([
+    [ SetRateLimitInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['window', 'u64'],
+            ['lamportCap', {kind: 'option', type: 'u64'}],
+            ['mintCaps', [MintCap]],
+        ],
+    }],
+    [ MintCap, {
+        kind: 'struct',
+        fields: [
+            ['mint', [32]],
+            ['cap', 'u64'],
+        ],
+    }]
+]);
+
+export class PauseInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(PauseInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(PauseInstructionSchema, PauseInstruction, buffer);
+    };
+}
+
+const PauseInstructionSchema = new Map([
+    [ PauseInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
+
+export class UnpauseInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(UnpauseInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(UnpauseInstructionSchema, UnpauseInstruction, buffer);
+    };
+}
+
+const UnpauseInstructionSchema = new Map([
+    [ UnpauseInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
+
+export class Config extends Assignable {
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserializeUnchecked(ConfigSchema, Config, buffer);
+    };
+}
+
+const ConfigSchema = new Map([
+    [ Config, {
+        kind: 'struct',
+        fields: [
+            ['accountType', 'u8'],
+            ['bump', 'u8'],
+            ['admin', [32]],
+            ['emergencyStop', 'u8'],
+            ['maxMintsPerExecute', 'u8'],
+        ],
+    }]
+]);
+
+export class InitConfigInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(InitConfigInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(InitConfigInstructionSchema, InitConfigInstruction, buffer);
+    };
+}
+
+const InitConfigInstructionSchema = new Map([
+    [ InitConfigInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['bump', 'u8'],
+            ['maxMintsPerExecute', 'u8'],
+        ],
+    }]
+]);
+
+export class UpdateConfigInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(UpdateConfigInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(UpdateConfigInstructionSchema, UpdateConfigInstruction, buffer);
+    };
+}
+
+const UpdateConfigInstructionSchema = new Map([
+    [ UpdateConfigInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+            ['admin', [32]],
+            ['emergencyStop', 'u8'],
+            ['maxMintsPerExecute', 'u8'],
+        ],
+    }]
+]);
+
+export class MigrateForwardInstruction extends Assignable {
+    toBuffer() { return Buffer.from(borsh.serialize(MigrateForwardInstructionSchema, this)) }
+
+    static fromBuffer(buffer: Buffer) {
+        return borsh.deserialize(MigrateForwardInstructionSchema, MigrateForwardInstruction, buffer);
+    };
+}
+
+const MigrateForwardInstructionSchema = new Map([
+    [ MigrateForwardInstruction, {
+        kind: 'struct',
+        fields: [
+            ['instruction', 'u8'],
+        ],
+    }]
+]);
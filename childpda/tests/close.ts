@@ -0,0 +1,115 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForward, deriveForwardPda} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {
+    createInitializeTransferFeeConfigInstruction,
+    createMint,
+    ExtensionType,
+    getAssociatedTokenAddressSync,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    transferCheckedWithFee
+} from "@solana/spl-token";
+import {createAndFundAta, createMintWithExtensions} from "./fns/createToken";
+
+describe("close instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+
+    let destination, quarantine, authority, rentRecipient, forwardAccount, mint, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        rentRecipient = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        await deposit(payer, authority.publicKey, LAMPORTS_PER_SOL / 10, connection);
+        mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should forward sol and return the rent when closed", async () => {
+        const forwardRent = await connection.getBalance(forwardAccount.publicKey);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        const forwardAmount = LAMPORTS_PER_SOL / 100;
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, rentRecipient.publicKey, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have closed");
+        }
+        const destinationBalanceAfter = await connection.getBalance(destination.publicKey);
+        expect(destinationBalanceAfter - destinationBalanceBefore, "destination balance").to.equal(forwardAmount);
+        expect(await connection.getBalance(rentRecipient.publicKey), "rent recipient balance").to.equal(forwardRent);
+        expect(await connection.getAccountInfo(forwardAccount.publicKey), "forward account").to.be.null;
+    });
+
+    it("Should forward tokens and close the forward ATAs when closed", async () => {
+        const forwardRent = await connection.getBalance(forwardAccount.publicKey);
+        const tokenAmount = 1000;
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const ataRent = await connection.getBalance(forwardAta);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, rentRecipient.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have closed");
+        }
+        const info = await connection.getTokenAccountBalance(destinationAta);
+        expect(info.value.uiAmount, "destination token balance").to.equal(tokenAmount);
+        expect(await connection.getAccountInfo(forwardAta), "forward ata").to.be.null;
+        expect(await connection.getBalance(rentRecipient.publicKey), "rent recipient balance").to.equal(forwardRent + ataRent);
+    });
+
+    it("Should close the forward but leave the forward ATA open while it holds withheld fees", async () => {
+        const tokenAmount = 1000;
+        const feeBps = 100;
+        const feeMint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferFeeConfig], mint =>
+            [createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, mintAuthority.publicKey, feeBps, BigInt(tokenAmount), TOKEN_2022_PROGRAM_ID)]);
+        const payerAta = await createAndFundAta(feeMint, payer.publicKey, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const forwardAta = await createAndFundAta(feeMint, forwardPda, 0, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(feeMint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        await transferCheckedWithFee(connection, payer, payerAta, feeMint, forwardAta, payer, BigInt(tokenAmount), 0, BigInt(tokenAmount * feeBps / 10000), [], undefined, TOKEN_2022_PROGRAM_ID);
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, rentRecipient.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, feeMint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have closed");
+        }
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount, "forward ata").to.equal(0);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "destination token balance").to.be.greaterThan(0);
+        expect(await connection.getAccountInfo(forwardAccount.publicKey), "forward account").to.be.null;
+    });
+
+    it("Should not close without the authority", async () => {
+        const bogusAuthority = Keypair.generate();
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, bogusAuthority, rentRecipient.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have closed")
+    });
+
+    it("Should be able to re-create the forward once closed", async () => {
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, rentRecipient.publicKey, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have re-created the forward");
+        }
+    });
+});
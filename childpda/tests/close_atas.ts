@@ -0,0 +1,84 @@
+import {Connection, Keypair} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens, executeWithTokensClosingAtas} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {
+    createInitializeTransferFeeConfigInstruction,
+    createMint,
+    ExtensionType,
+    getAssociatedTokenAddressSync,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    transferCheckedWithFee
+} from "@solana/spl-token";
+import {createAndFundAta, createMintWithExtensions} from "./fns/createToken";
+const forwardSol = true;
+
+describe("close emptied forward atas tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const tokenAmount = 1000;
+
+    let destination, quarantine, forwardAccount, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should close the emptied forward ATA and return its rent to the destination", async () => {
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+        const forwardAtaRent = await connection.getBalance(forwardAta);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await executeWithTokensClosingAtas(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getAccountInfo(forwardAta), "forward ata").to.be.null;
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore, "destination rent").to.equal(forwardAtaRent);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "destination").to.equal(tokenAmount);
+    });
+
+    it("Should leave the emptied forward ATA open without the close option", async () => {
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount, "forward").to.equal(0);
+    });
+
+    it("Should leave the forward ATA open while it holds withheld fees", async () => {
+        const feeBps = 100;
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferFeeConfig], mint =>
+            [createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, mintAuthority.publicKey, feeBps, BigInt(tokenAmount), TOKEN_2022_PROGRAM_ID)]);
+        const payerAta = await createAndFundAta(mint, payer.publicKey, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const forwardAta = await createAndFundAta(mint, forwardPda, 0, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        await transferCheckedWithFee(connection, payer, payerAta, mint, forwardAta, payer, BigInt(tokenAmount), 0, BigInt(tokenAmount * feeBps / 10000), [], undefined, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokensClosingAtas(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount, "forward").to.equal(0);
+    });
+});
@@ -0,0 +1,138 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveConfigPda, deriveForwardPda, execute, executeWithTokens, initConfig, pause, updateConfig} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {afterEach, before, beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Config} from "./classes/classes";
+const forwardSol = true;
+
+describe("config instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+    const maxMintsPerExecute = 10;
+    const [configPda] = deriveConfigPda(program.publicKey);
+
+    let destination, quarantine, forwardAccount, authority, forwardPda, forwardBump;
+
+    //the config pda is funded before it is initialised, as anyone could fund it first
+    before("init config", async () => {
+        const configAccount = await connection.getAccountInfo(configPda);
+        if (!configAccount || !configAccount.owner.equals(program.publicKey)) {
+            await deposit(payer, configPda, LAMPORTS_PER_SOL / 100, connection);
+            await initConfig(payer, maxMintsPerExecute, program, payer, connection);
+        }
+    });
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+    });
+
+    afterEach("reset config", async () => {
+        await updateConfig(payer.publicKey, false, maxMintsPerExecute, payer, program, payer, connection);
+    });
+
+    it("Should not init the config if not the program upgrade authority", async () => {
+        try {
+            await initConfig(Keypair.generate(), maxMintsPerExecute, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have initialised")
+    });
+
+    it("Should not init the config at a non canonical bump", async () => {
+        let [, bump] = deriveConfigPda(program.publicKey);
+        let nonCanonicalPda = null;
+        while (!nonCanonicalPda) {
+            bump--;
+            try {
+                nonCanonicalPda = PublicKey.createProgramAddressSync([Buffer.from("config"), Buffer.from([bump])], program.publicKey);
+            } catch (e) {
+                //on the curve, try the next bump
+            }
+        }
+        try {
+            await initConfig(payer, maxMintsPerExecute, program, payer, connection, [nonCanonicalPda, bump]);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1e")
+            return;
+        }
+        expect.fail("Should not have initialised")
+    });
+
+    it("Should update the config", async () => {
+        try {
+            await updateConfig(payer.publicKey, false, 3, payer, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have updated");
+        }
+        const config = Config.fromBuffer((await connection.getAccountInfo(configPda)).data);
+        expect(Buffer.from(config.admin).equals(payer.publicKey.toBuffer())).to.equal(true);
+        expect(config.emergencyStop).to.equal(0);
+        expect(config.maxMintsPerExecute).to.equal(3);
+    });
+
+    it("Should not execute while the emergency stop is set", async () => {
+        await updateConfig(payer.publicKey, true, maxMintsPerExecute, payer, program, payer, connection);
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1d")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not update the config if not the admin", async () => {
+        try {
+            await updateConfig(payer.publicKey, true, maxMintsPerExecute, Keypair.generate(), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have updated")
+    });
+
+    it("Should not forward more mints than the maximum per execute", async () => {
+        const mint1 = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const mint2 = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const destinationAta1 = await createAndFundAta(mint1, destination.publicKey, 0, payer, mintAuthority, connection);
+        const destinationAta2 = await createAndFundAta(mint2, destination.publicKey, 0, payer, mintAuthority, connection);
+        const forwardAta1 = await createAndFundAta(mint1, forwardPda, 100, payer, mintAuthority, connection);
+        const forwardAta2 = await createAndFundAta(mint2, forwardPda, 100, payer, mintAuthority, connection);
+        await updateConfig(payer.publicKey, false, 1, payer, program, payer, connection);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint1, forwardAta1, destinationAta1, mint2, forwardAta2, destinationAta2);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1f")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not load the config as a forward", async () => {
+        try {
+            await pause(configPda, payer, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x20")
+            return;
+        }
+        expect.fail("Should not have paused")
+    });
+});
@@ -1,6 +1,6 @@
 import {Forward} from "./classes/classes";
 import {expect} from "chai";
-import {Connection, Keypair, PublicKey} from "@solana/web3.js";
+import {Connection, Keypair, PublicKey, SystemProgram} from "@solana/web3.js";
 import {createKeypairFromFile} from "./fns/createKeyPair";
 import {beforeEach} from "mocha";
 import {createForward, deriveForwardPda} from "./fns/forwardFns";
@@ -13,10 +13,11 @@ const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/i
 const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
 const forwardId = 123456;
 
-let destination, forwardAccount, forwardPda, forwardBump;
+let destination, quarantine, forwardAccount, forwardPda, forwardBump;
 
 beforeEach("setup", async () => {
     destination = Keypair.generate();
+    quarantine = Keypair.generate();
     forwardAccount = Keypair.generate();
 });
 
@@ -26,7 +27,7 @@ describe("create instruction tests", () => {
 
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             console.log(e);
             expect.fail("Should have created forward");
@@ -35,22 +36,24 @@ describe("create instruction tests", () => {
         const forwardInfo = await connection.getAccountInfo(forwardAccount.publicKey);
         const fwd = Forward.fromBuffer(forwardInfo.data);
 
-        expect(new PublicKey(fwd.destination), "destination").to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd.recipients[0].destination), "destination").to.deep.equal(destination.publicKey);
         expect(new PublicKey(fwd.forwardPda), "forward account").to.deep.equal(forwardPda);
         expect(fwd.bump).to.equal(forwardBump);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(payer.publicKey);
+        expect(new PublicKey(fwd.quarantine), "quarantine").to.deep.equal(quarantine.publicKey);
     });
 
     it("Should not create forward if already exists", async () => {
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             console.log(e);
             expect.fail("Should have created forward");
             return;
         }
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x6")
             return;
@@ -63,8 +66,8 @@ describe("create instruction tests", () => {
         const [forwardPda1, forwardBump1] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
         const [forwardPda2, forwardBump2] = deriveForwardPda(forwardAccount2.publicKey, program.publicKey);
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump1, forwardPda1, program, payer, connection);
-            await createForward(forwardAccount2, destination.publicKey, forwardBump2, forwardPda2, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump1, forwardPda1, program, payer, connection);
+            await createForward(forwardAccount2, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump2, forwardPda2, program, payer, connection);
         } catch (e) {
             console.log(e)
             expect.fail("Should have created forward");
@@ -75,11 +78,11 @@ describe("create instruction tests", () => {
         const fwd1 = Forward.fromBuffer(forwardInfo1.data);
         const fwd2 = Forward.fromBuffer(forwardInfo2.data);
 
-        expect(new PublicKey(fwd1.destination), "destination").to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd1.recipients[0].destination), "destination").to.deep.equal(destination.publicKey);
         expect(new PublicKey(fwd1.forwardPda), "forward account").to.deep.equal(forwardPda1);
         expect(fwd1.bump).to.equal(forwardBump1);
 
-        expect(new PublicKey(fwd2.destination), "destination").to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd2.recipients[0].destination), "destination").to.deep.equal(destination.publicKey);
         expect(new PublicKey(fwd2.forwardPda), "forward account").to.deep.equal(forwardPda2);
         expect(fwd2.bump).to.equal(forwardBump2);
 
@@ -91,7 +94,7 @@ describe("create instruction tests", () => {
         let destAtaToken1 = await createAndFundAta(mint, destination.publicKey, 10, payer, mintAuthority, connection);
         const [forwardToTokenPda, forwardBump] = deriveForwardPda(destAtaToken1, program.publicKey);
         try {
-            await createForward(forwardAccount, destAtaToken1, forwardBump, forwardToTokenPda, program, payer, connection);
+            await createForward(forwardAccount, destAtaToken1, quarantine.publicKey, payer.publicKey, forwardBump, forwardToTokenPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x1")
             return;
@@ -99,11 +102,36 @@ describe("create instruction tests", () => {
         expect.fail("Should not have created forward")
     });
 
+    it("The quarantine account should not be an ATA", async () => {
+        const mintAuthority = Keypair.generate();
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        let quarantineAta = await createAndFundAta(mint, quarantine.publicKey, 10, payer, mintAuthority, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        try {
+            await createForward(forwardAccount, destination.publicKey, quarantineAta, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x2")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+
+    it("The quarantine account should not be the system program", async () => {
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        try {
+            await createForward(forwardAccount, destination.publicKey, SystemProgram.programId, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xe")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+
     it("Should fail to re-initialise the forward with a different destination", async () => {
 
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             console.log(e);
             expect.fail("Should have created forward")
@@ -112,7 +140,7 @@ describe("create instruction tests", () => {
         const bogusDestination = Keypair.generate();
         await initialiseAccountWithMinimumBalance(bogusDestination.publicKey, payer, connection);
         try {
-            await createForward(forwardAccount, bogusDestination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardAccount, bogusDestination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x6")
             return;
@@ -125,7 +153,7 @@ describe("create instruction tests", () => {
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
 
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, bogusPda.publicKey, program, payer, connection);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, bogusPda.publicKey, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x7")
             return;
@@ -139,7 +167,7 @@ describe("create instruction tests", () => {
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
 
         try {
-            await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection, bogusSystemProgram);
+            await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, bogusSystemProgram);
         } catch (e) {
             expect(e.message).to.contain("incorrect program id for instruction")
             return;
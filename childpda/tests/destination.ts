@@ -0,0 +1,97 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {acceptDestination, createForward, deriveForwardPda, execute, updateDestination} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Forward} from "./classes/classes";
+
+describe("destination instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const forwardSol = true;
+
+    let destination, newDestination, quarantine, authority, forwardAccount, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        newDestination = Keypair.generate();
+        authority = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        await initialiseAccountWithMinimumBalance(newDestination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should forward to the new destination once accepted", async () => {
+        const newDestinationBalanceBefore = await connection.getBalance(newDestination.publicKey);
+        const forwardAmount = LAMPORTS_PER_SOL / 100;
+        try {
+            await updateDestination(forwardAccount.publicKey, authority, newDestination.publicKey, program, payer, connection);
+            await acceptDestination(forwardAccount.publicKey, newDestination, program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, newDestination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const newDestinationBalanceAfter = await connection.getBalance(newDestination.publicKey);
+        expect(newDestinationBalanceAfter - newDestinationBalanceBefore).to.equal(forwardAmount);
+
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(new PublicKey(fwd.recipients[0].destination), "destination").to.deep.equal(newDestination.publicKey);
+        expect(fwd.pendingDestination, "pending destination").to.be.undefined;
+    });
+
+    it("Should keep forwarding to the current destination until accepted", async () => {
+        try {
+            await updateDestination(forwardAccount.publicKey, authority, newDestination.publicKey, program, payer, connection);
+            await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+            await execute(forwardPda, newDestination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x3")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should only be accepted by the proposed destination", async () => {
+        try {
+            await updateDestination(forwardAccount.publicKey, authority, newDestination.publicKey, program, payer, connection);
+            await acceptDestination(forwardAccount.publicKey, Keypair.generate(), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x3")
+            return;
+        }
+        expect.fail("Should not have accepted the destination")
+    });
+
+    it("Should not be updated without the authority", async () => {
+        try {
+            await updateDestination(forwardAccount.publicKey, Keypair.generate(), newDestination.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have updated the destination")
+    });
+
+    it("The new destination should not be an ATA", async () => {
+        const mintAuthority = Keypair.generate();
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const newDestinationAta = await createAndFundAta(mint, newDestination.publicKey, 10, payer, mintAuthority, connection);
+        try {
+            await updateDestination(forwardAccount.publicKey, authority, newDestinationAta, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1")
+            return;
+        }
+        expect.fail("Should not have updated the destination")
+    });
+});
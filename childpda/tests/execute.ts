@@ -21,14 +21,15 @@ describe("execute instruction tests", () => {
     const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
     const mintAuthority = Keypair.generate();
 
-    let destination, forwardAccount, mint, forwardPda, forwardBump;
+    let destination, quarantine, forwardAccount, mint, forwardPda, forwardBump;
 
     beforeEach("setup", async () => {
         destination = Keypair.generate();
+        quarantine = Keypair.generate();
         forwardAccount = Keypair.generate();
         mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
-        await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
     });
 
     it("Should transfer sol when executed", async () => {
@@ -13,19 +13,20 @@ const program = createKeypairFromFile('./program/target/so/solana_forward_childp
 const forwardId = 123456;
 const forwardSol = true;
 
-let destination, forwardAccount, forwardPda, forwardBump, mint, mintAuthority;
+let destination, quarantine, forwardAccount, forwardPda, forwardBump, mint, mintAuthority;
 
 describe("execute validation tests", () => {
 
     beforeEach("setup", async () => {
         forwardAccount = Keypair.generate();
         destination = Keypair.generate();
+        quarantine = Keypair.generate();
         mintAuthority = Keypair.generate();
 
         mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
 
         [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
-        await createForward(forwardAccount, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
     });
 
     it("Should not transfer sol to an invalid destination", async () => {
@@ -2,15 +2,18 @@ import {
     Commitment,
     ConfirmOptions,
     Connection,
+    Keypair,
     PublicKey,
     sendAndConfirmTransaction,
     Signer,
-    Transaction
+    SystemProgram,
+    Transaction,
+    TransactionInstruction
 } from "@solana/web3.js";
 import {
     ASSOCIATED_TOKEN_PROGRAM_ID,
-    createAssociatedTokenAccountIdempotentInstruction, createMint,
-    getAssociatedTokenAddressSync, mintTo, TOKEN_PROGRAM_ID
+    createAssociatedTokenAccountIdempotentInstruction, createInitializeMintInstruction, createMint, ExtensionType,
+    getAssociatedTokenAddressSync, getMintLen, mintTo, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID
 } from "@solana/spl-token";
 
 export async function createATA(
@@ -45,3 +48,30 @@ export async function createAndFundAta(mint, to, mintAmount, payer, mintAuthorit
     await mintTo(connection, payer, mint, ata, mintAuthority, mintAmount, [], undefined, programId);
     return ata;
 }
+
+//Creates a token 2022 mint with the given mint extensions, extensionInstructions initialise the extensions before the mint
+export async function createMintWithExtensions(
+    connection: Connection,
+    payer: Signer,
+    mintAuthority: PublicKey,
+    extensions: ExtensionType[],
+    extensionInstructions: (mint: PublicKey) => TransactionInstruction[],
+    decimals = 0,
+): Promise<PublicKey> {
+
+    const mint = Keypair.generate();
+    const mintLen = getMintLen(extensions);
+    const transaction = new Transaction().add(
+        SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: mint.publicKey,
+            space: mintLen,
+            lamports: await connection.getMinimumBalanceForRentExemption(mintLen),
+            programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        ...extensionInstructions(mint.publicKey),
+        createInitializeMintInstruction(mint.publicKey, decimals, mintAuthority, null, TOKEN_2022_PROGRAM_ID),
+    );
+    await sendAndConfirmTransaction(connection, transaction, [payer, mint]);
+    return mint.publicKey;
+}
@@ -1,14 +1,57 @@
 import {
     PublicKey,
     sendAndConfirmTransaction,
+    StakeProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_STAKE_HISTORY_PUBKEY,
     SystemProgram,
     Transaction,
     TransactionInstruction
 } from "@solana/web3.js";
 import {Buffer} from "buffer";
 import {toLeArray} from "./toLeArray";
-import {CreateForwardInstruction, ExecuteForwardInstruction, ForwardInstructions} from "../classes/classes";
-import {ASSOCIATED_TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {
+    AcceptDestinationInstruction,
+    Bounty,
+    CloseForwardInstruction,
+    CreateForwardInstruction,
+    CreateForwardV2Instruction,
+    ExecuteForwardInstruction,
+    ExecuteForwardV2Instruction,
+    ForwardInstructions,
+    HarvestWithheldFeesInstruction,
+    InitConfigInstruction,
+    MigrateForwardInstruction,
+    MintCap,
+    MintPolicy,
+    MintRoute,
+    MintThreshold,
+    NotBefore,
+    PauseInstruction,
+    QuarantineForwardInstruction,
+    SetAuthorityInstruction,
+    SetBountyInstruction,
+    SetMintPolicyInstruction,
+    SetMintRoutesInstruction,
+    SetNotBeforeInstruction,
+    SetRateLimitInstruction,
+    SetThresholdsInstruction,
+    SetVestingInstruction,
+    UnpauseInstruction,
+    UpdateConfigInstruction,
+    UpdateDestinationInstruction
+} from "../classes/classes";
+import {
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    createExecuteInstruction,
+    getExtraAccountMetaAddress,
+    getExtraAccountMetas,
+    getMint,
+    getTransferHook,
+    resolveExtraAccountMeta,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID
+} from "@solana/spl-token";
 
 export function deriveForwardPda(forwardPubkey: PublicKey, programId) {
     return PublicKey.findProgramAddressSync(
@@ -18,31 +61,107 @@ export function deriveForwardPda(forwardPubkey: PublicKey, programId) {
 }
 
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+export function deriveConfigPda(programId) {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        programId,
+    )
+}
+
+//every instruction other than the config instructions is followed by the config account
+function forwardInstruction({keys, programId, data}) {
+    return new TransactionInstruction({
+        keys: [
+            ...keys,
+            {pubkey: deriveConfigPda(programId)[0], isSigner: false, isWritable: false},
+        ],
+        programId: programId,
+        data: data,
+    });
+}
+
+//destinations can be a single destination or one per recipient, in order
+function destinationKeys(destinations, isWritable = true) {
+    return [destinations].flat().map(destination =>
+        ({pubkey: destination instanceof PublicKey ? destination : destination.publicKey, isSigner: false, isWritable: isWritable}));
+}
+
+//a mint of the other token program is followed by that token program, which is read only
+function tokenKeys(tokenAccounts: PublicKey[]) {
+    return tokenAccounts.map(key =>
+        ({pubkey: key, isSigner: false, isWritable: !key.equals(TOKEN_PROGRAM_ID) && !key.equals(TOKEN_2022_PROGRAM_ID)}));
+}
+
 export async function createForward(
     forwardAccount,
-    destinationPublicKey: PublicKey,
+    destinationPublicKeys: PublicKey | PublicKey[],
+    quarantinePublicKey: PublicKey,
+    authorityPublicKey: PublicKey,
     forwardBump,
     forwardPda,
     program,
     payer,
     connection,
-    systemProgram: any = SystemProgram.programId) {
+    systemProgram: any = SystemProgram.programId,
+    executeRequiresAuthority = false,
+    recipientBps: number[] = [10000],
+    dustRecipient = 0) {
 
-    let ix = new TransactionInstruction({
+    const data = new CreateForwardInstruction({
+        instruction: ForwardInstructions.CreateForward,
+        forwardPda: forwardPda.toBuffer(),
+        bump: forwardBump,
+        authority: authorityPublicKey.toBuffer(),
+        executeRequiresAuthority: executeRequiresAuthority ? 1 : 0,
+        recipientBps: recipientBps,
+        dustRecipient: dustRecipient
+    }).toBuffer();
+    await sendCreateForward(data, forwardAccount, destinationPublicKeys, quarantinePublicKey, program, payer, connection, systemProgram);
+}
+
+export async function createForwardWithNotBefore(
+    forwardAccount,
+    destinationPublicKeys: PublicKey | PublicKey[],
+    quarantinePublicKey: PublicKey,
+    authorityPublicKey: PublicKey,
+    forwardBump,
+    forwardPda,
+    notBefore: NotBefore,
+    program,
+    payer,
+    connection,
+    systemProgram: any = SystemProgram.programId,
+    executeRequiresAuthority = false,
+    recipientBps: number[] = [10000],
+    dustRecipient = 0) {
+
+    const data = new CreateForwardV2Instruction({
+        instruction: ForwardInstructions.CreateForwardV2,
+        forwardPda: forwardPda.toBuffer(),
+        bump: forwardBump,
+        authority: authorityPublicKey.toBuffer(),
+        executeRequiresAuthority: executeRequiresAuthority ? 1 : 0,
+        recipientBps: recipientBps,
+        dustRecipient: dustRecipient,
+        notBefore: notBefore
+    }).toBuffer();
+    await sendCreateForward(data, forwardAccount, destinationPublicKeys, quarantinePublicKey, program, payer, connection, systemProgram);
+}
+
+async function sendCreateForward(data: Buffer, forwardAccount, destinationPublicKeys: PublicKey | PublicKey[], quarantinePublicKey: PublicKey, program, payer, connection, systemProgram) {
+    let ix = forwardInstruction({
         keys: [
             {pubkey: forwardAccount.publicKey, isSigner: true, isWritable: true},
-            {pubkey: destinationPublicKey, isSigner: false, isWritable: false},
+            ...destinationKeys(destinationPublicKeys, false).slice(0, 1),
+            {pubkey: quarantinePublicKey, isSigner: false, isWritable: false},
             {pubkey: payer.publicKey, isSigner: true, isWritable: true},
-            {pubkey: systemProgram, isSigner: false, isWritable: false}
+            {pubkey: systemProgram, isSigner: false, isWritable: false},
+            ...destinationKeys(destinationPublicKeys, false).slice(1),
         ],
         programId: program.publicKey,
-        data: (
-            new CreateForwardInstruction({
-                instruction: ForwardInstructions.CreateForward,
-                forwardPda: forwardPda.toBuffer(),
-                bump: forwardBump
-            })
-        ).toBuffer(),
+        data: data,
     });
     await sendAndConfirmTransaction(
         connection,
@@ -51,15 +170,20 @@ export async function createForward(
     );
 }
 
-export async function execute(forwardPda, destination, forwardAccountPublicKey, program, payer, connection, transfer_sol) {
+export async function execute(forwardPda, destination, forwardAccountPublicKey, program, payer, connection, transfer_sol, signer?) {
 
-    let ix = new TransactionInstruction({
-        keys: [
-            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
-            {pubkey: forwardPda, isSigner: false, isWritable: true},
-            {pubkey: destination.publicKey, isSigner: false, isWritable: true},
-            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
-        ],
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+    ];
+    if (signer) {
+        keys.push({pubkey: signer.publicKey, isSigner: true, isWritable: true});
+    }
+
+    let ix = forwardInstruction({
+        keys: keys,
         programId: program.publicKey,
         data: (
             new ExecuteForwardInstruction({
@@ -68,6 +192,40 @@ export async function execute(forwardPda, destination, forwardAccountPublicKey,
             })
         ).toBuffer(),
     });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        signer ? [payer, signer] : [payer]
+    );
+}
+
+//sweeps the stake accounts the forward pda is the withdrawer of, forwarding their inactive lamports as sol
+export async function executeSweepingStake(forwardPda, destination, forwardAccountPublicKey, program, payer, connection, ...stakeAccounts: PublicKey[]) {
+
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+        {pubkey: SYSVAR_CLOCK_PUBKEY, isSigner: false, isWritable: false},
+        {pubkey: SYSVAR_STAKE_HISTORY_PUBKEY, isSigner: false, isWritable: false},
+        {pubkey: StakeProgram.programId, isSigner: false, isWritable: false},
+        ...stakeAccounts.map(key => ({pubkey: key, isSigner: false, isWritable: true})),
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+    ];
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: (
+            new ExecuteForwardV2Instruction({
+                instruction: ForwardInstructions.ExecuteV2,
+                forward_sol: 1,
+                close_emptied_atas: 0,
+                unwrap_native: 0,
+                stake_accounts: stakeAccounts.length
+            })
+        ).toBuffer(),
+    });
     await sendAndConfirmTransaction(
         connection,
         new Transaction().add(ix),
@@ -75,7 +233,7 @@ export async function execute(forwardPda, destination, forwardAccountPublicKey,
     );
 }
 
-//tokenAccounts = mint1, forwardAta1, destinationAta1, mint2, forwardAta2, destinationAta2.....
+//tokenAccounts = mint1, forwardAta1, destinationAta1 (one per recipient), mint2, forwardAta2, destinationAta2.....
 export async function executeWithTokens(
     forwardSol,
     forwardPda,
@@ -86,12 +244,62 @@ export async function executeWithTokens(
     connection,
     token_program,
     ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardInstruction({
+        instruction: ForwardInstructions.Execute,
+        forward_sol: forwardSol ? 1 : 0
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, forwardAccountPublicKey, program, payer, connection, token_program, tokenAccounts);
+}
+
+//closes the forward atas emptied by the execute, returning their rent to the destination of the first recipient
+export async function executeWithTokensClosingAtas(
+    forwardSol,
+    forwardPda,
+    destination,
+    forwardAccountPublicKey,
+    program,
+    payer,
+    connection,
+    token_program,
+    ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardV2Instruction({
+        instruction: ForwardInstructions.ExecuteV2,
+        forward_sol: forwardSol ? 1 : 0,
+        close_emptied_atas: 1,
+        unwrap_native: 0,
+        stake_accounts: 0
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, forwardAccountPublicKey, program, payer, connection, token_program, tokenAccounts);
+}
+
+//unwraps the forward wsol ata, forwarding its lamports as sol
+export async function executeWithTokensUnwrappingNative(
+    forwardSol,
+    forwardPda,
+    destination,
+    forwardAccountPublicKey,
+    program,
+    payer,
+    connection,
+    token_program,
+    ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardV2Instruction({
+        instruction: ForwardInstructions.ExecuteV2,
+        forward_sol: forwardSol ? 1 : 0,
+        close_emptied_atas: 0,
+        unwrap_native: 1,
+        stake_accounts: 0
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, forwardAccountPublicKey, program, payer, connection, token_program, tokenAccounts);
+}
+
+async function sendExecuteWithTokens(data: Buffer, forwardPda, destination, forwardAccountPublicKey, program, payer, connection, token_program, tokenAccounts: PublicKey[]) {
 
-    let tokenTransactionAccounts = tokenAccounts.map(key  => ({pubkey: key, isSigner: false, isWritable: true}));
+    let tokenTransactionAccounts = tokenKeys(tokenAccounts);
     let keys = [
         {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
         {pubkey: forwardPda, isSigner: false, isWritable: true},
-        {pubkey: destination.publicKey, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
         {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
 
         {pubkey: payer.publicKey, isSigner: true, isWritable: true},
@@ -100,13 +308,580 @@ export async function executeWithTokens(
         ...tokenTransactionAccounts,
     ];
 
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: data,
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer]
+    );
+}
+
+
+//tokenAccounts = mint1, forwardAta1, quarantineAta1, mint2, forwardAta2, quarantineAta2.....
+export async function quarantine(
+    quarantineSol,
+    forwardPda,
+    quarantinePublicKey: PublicKey,
+    forwardAccountPublicKey,
+    authority,
+    program,
+    payer,
+    connection,
+    token_program?: PublicKey,
+    ...tokenAccounts: PublicKey[]) {
+
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        {pubkey: quarantinePublicKey, isSigner: false, isWritable: true},
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+    ];
+    if (token_program) {
+        keys.push(
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: token_program, isSigner: false, isWritable: false},
+            {pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+            ...tokenKeys(tokenAccounts),
+        );
+    }
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: (
+            new QuarantineForwardInstruction({
+                instruction: ForwardInstructions.Quarantine,
+                quarantine_sol: quarantineSol ? 1 : 0
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setAuthority(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    newAuthorityPublicKey: PublicKey,
+    executeRequiresAuthority: boolean,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: newAuthorityPublicKey, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetAuthorityInstruction({
+                instruction: ForwardInstructions.SetAuthority,
+                executeRequiresAuthority: executeRequiresAuthority ? 1 : 0
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//tokenAccounts = mint1, forwardAta1, destinationAta1 (one per recipient), mint2, forwardAta2, destinationAta2.....
+export async function closeForward(
+    forwardPda,
+    destination,
+    forwardAccountPublicKey,
+    authority,
+    rentRecipient: PublicKey,
+    program,
+    payer,
+    connection,
+    token_program?: PublicKey,
+    ...tokenAccounts: PublicKey[]) {
+
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        {pubkey: authority.publicKey, isSigner: true, isWritable: true},
+        {pubkey: rentRecipient, isSigner: false, isWritable: true},
+    ];
+    if (token_program) {
+        keys.push(
+            {pubkey: token_program, isSigner: false, isWritable: false},
+            {pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+            ...tokenKeys(tokenAccounts),
+        );
+    }
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: (
+            new CloseForwardInstruction({
+                instruction: ForwardInstructions.CloseForward,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function updateDestination(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    newDestinationPublicKey: PublicKey,
+    program,
+    payer,
+    connection,
+    index = 0) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            {pubkey: newDestinationPublicKey, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UpdateDestinationInstruction({
+                instruction: ForwardInstructions.UpdateDestination,
+                index: index
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function acceptDestination(
+    forwardAccountPublicKey: PublicKey,
+    newDestination,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: newDestination.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new AcceptDestinationInstruction({
+                instruction: ForwardInstructions.AcceptDestination,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, newDestination]
+    );
+}
+
+export async function setThresholds(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    minLamports: number,
+    mintThresholds: {mint: PublicKey, amount: number}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetThresholdsInstruction({
+                instruction: ForwardInstructions.SetThresholds,
+                minLamports: minLamports,
+                mintThresholds: mintThresholds.map(t => new MintThreshold({mint: t.mint.toBuffer(), amount: t.amount}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setBounty(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    bounty: Bounty,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetBountyInstruction({
+                instruction: ForwardInstructions.SetBounty,
+                bounty: bounty
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setMintPolicy(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    mintPolicy: MintPolicy,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetMintPolicyInstruction({
+                instruction: ForwardInstructions.SetMintPolicy,
+                mintPolicy: mintPolicy
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setMintRoutes(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    mintRoutes: {mint: PublicKey, destination: PublicKey}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            ...mintRoutes.map(r => ({pubkey: r.destination, isSigner: false, isWritable: false})),
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetMintRoutesInstruction({
+                instruction: ForwardInstructions.SetMintRoutes,
+                mintRoutes: mintRoutes.map(r => new MintRoute({mint: r.mint.toBuffer(), destination: r.destination.toBuffer()}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setNotBefore(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    notBefore: NotBefore,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetNotBeforeInstruction({
+                instruction: ForwardInstructions.SetNotBefore,
+                notBefore: notBefore
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//start and end are unix timestamps
+export async function setVesting(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    start: number,
+    end: number,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetVestingInstruction({
+                instruction: ForwardInstructions.SetVesting,
+                start: start,
+                end: end
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//window is in seconds, a null lamport cap and no mint caps removes the rate limit
+export async function setRateLimit(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    window: number,
+    lamportCap: number | null,
+    mintCaps: {mint: PublicKey, cap: number}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetRateLimitInstruction({
+                instruction: ForwardInstructions.SetRateLimit,
+                window: window,
+                lamportCap: lamportCap,
+                mintCaps: mintCaps.map(c => new MintCap({mint: c.mint.toBuffer(), cap: c.cap}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function pause(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new PauseInstruction({
+                instruction: ForwardInstructions.Pause,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function unpause(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UnpauseInstruction({
+                instruction: ForwardInstructions.Unpause,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//the admin must be the upgrade authority of the program
+export async function initConfig(
+    admin,
+    maxMintsPerExecute,
+    program,
+    payer,
+    connection,
+    [configPda, configBump] = deriveConfigPda(program.publicKey)) {
+
+    const [programData] = PublicKey.findProgramAddressSync([program.publicKey.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+    let ix = new TransactionInstruction({
+        keys: [
+            {pubkey: configPda, isSigner: false, isWritable: true},
+            {pubkey: admin.publicKey, isSigner: true, isWritable: true},
+            {pubkey: programData, isSigner: false, isWritable: false},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new InitConfigInstruction({
+                instruction: ForwardInstructions.InitConfig,
+                bump: configBump,
+                maxMintsPerExecute: maxMintsPerExecute,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, admin]
+    );
+}
+
+export async function updateConfig(
+    newAdminPublicKey: PublicKey,
+    emergencyStop: boolean,
+    maxMintsPerExecute,
+    admin,
+    program,
+    payer,
+    connection) {
+
     let ix = new TransactionInstruction({
+        keys: [
+            {pubkey: deriveConfigPda(program.publicKey)[0], isSigner: false, isWritable: true},
+            {pubkey: admin.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UpdateConfigInstruction({
+                instruction: ForwardInstructions.UpdateConfig,
+                admin: newAdminPublicKey.toBuffer(),
+                emergencyStop: emergencyStop ? 1 : 0,
+                maxMintsPerExecute: maxMintsPerExecute,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, admin]
+    );
+}
+
+//destination = the destination of an unversioned forward, signing to become its authority
+export async function migrateForward(
+    forwardAccountPublicKey: PublicKey,
+    program,
+    payer,
+    connection,
+    destination?) {
+
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+    ];
+    if (destination) {
+        keys.push({pubkey: destination.publicKey, isSigner: true, isWritable: false});
+    }
+
+    let ix = forwardInstruction({
         keys: keys,
         programId: program.publicKey,
         data: (
-            new ExecuteForwardInstruction({
-                instruction: ForwardInstructions.Execute,
-                forward_sol: forwardSol ? 1 : 0
+            new MigrateForwardInstruction({
+                instruction: ForwardInstructions.MigrateForward,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        destination ? [payer, destination] : [payer]
+    );
+}
+
+//mintAndForwardAtas is the mint followed by the forward ATA, for each mint to harvest
+export async function harvestWithheldFees(forwardAccountPublicKey: PublicKey, forwardPda, program, payer, connection, ...mintAndForwardAtas: PublicKey[]) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: forwardPda, isSigner: false, isWritable: true},
+            {pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false},
+            ...mintAndForwardAtas.map(key  => ({pubkey: key, isSigner: false, isWritable: true})),
+        ],
+        programId: program.publicKey,
+        data: (
+            new HarvestWithheldFeesInstruction({
+                instruction: ForwardInstructions.HarvestWithheldFees,
             })
         ).toBuffer(),
     });
@@ -117,3 +892,18 @@ export async function executeWithTokens(
     );
 }
 
+//The transfer hook accounts that follow the targets of a mint: the hook program, the extra account metas account and each extra account
+//The extra accounts are resolved for a transfer of the amount from the source ATA to the destination ATA, none are needed if the mint has no transfer hook
+export async function transferHookAccounts(connection, mint: PublicKey, source: PublicKey, destination: PublicKey, owner: PublicKey, amount, tokenProgram = TOKEN_2022_PROGRAM_ID) {
+    const transferHook = getTransferHook(await getMint(connection, mint, undefined, tokenProgram));
+    if (!transferHook || transferHook.programId.equals(PublicKey.default)) {
+        return [];
+    }
+    const extraAccountMetasAddress = getExtraAccountMetaAddress(mint, transferHook.programId);
+    const extraAccountMetas = getExtraAccountMetas(await connection.getAccountInfo(extraAccountMetasAddress));
+    const executeInstruction = createExecuteInstruction(transferHook.programId, source, mint, destination, owner, extraAccountMetasAddress, BigInt(amount));
+    for (const extraAccountMeta of extraAccountMetas) {
+        executeInstruction.keys.push(await resolveExtraAccountMeta(connection, extraAccountMeta, executeInstruction.keys, executeInstruction.data, transferHook.programId));
+    }
+    return [transferHook.programId, extraAccountMetasAddress, ...executeInstruction.keys.slice(5).map(meta => meta.pubkey)];
+}
@@ -0,0 +1,90 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForward, deriveForwardPda, executeWithTokens, setMintPolicy} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {MintPolicy} from "./classes/classes";
+const forwardSol = true;
+
+describe("mint policy instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const tokenAmount = 1000;
+
+    let destination, quarantine, forwardAccount, authority, mint, forwardPda, forwardBump, forwardAta, destinationAta;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        await deposit(payer, authority.publicKey, LAMPORTS_PER_SOL / 10, connection);
+        mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+    });
+
+    it("Should forward a mint on the allowlist", async () => {
+        try {
+            await setMintPolicy(forwardAccount.publicKey, authority, new MintPolicy({enum: 'allow', allow: [mint.toBuffer()]}), program, payer, connection);
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(tokenAmount);
+    });
+
+    it("Should not forward a mint missing from the allowlist", async () => {
+        try {
+            await setMintPolicy(forwardAccount.publicKey, authority, new MintPolicy({enum: 'allow', allow: [Keypair.generate().publicKey.toBuffer()]}), program, payer, connection);
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x13")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not forward a mint on the denylist", async () => {
+        try {
+            await setMintPolicy(forwardAccount.publicKey, authority, new MintPolicy({enum: 'deny', deny: [mint.toBuffer()]}), program, payer, connection);
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x13")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should still forward a denied mint when closed", async () => {
+        try {
+            await setMintPolicy(forwardAccount.publicKey, authority, new MintPolicy({enum: 'deny', deny: [mint.toBuffer()]}), program, payer, connection);
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, authority.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have closed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(tokenAmount);
+    });
+
+    it("Should not set a mint policy with too many mints", async () => {
+        const mints = Array.from({length: 6}, () => Keypair.generate().publicKey.toBuffer());
+        try {
+            await setMintPolicy(forwardAccount.publicKey, authority, new MintPolicy({enum: 'deny', deny: mints}), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x12")
+            return;
+        }
+        expect.fail("Should not have set the mint policy")
+    });
+});
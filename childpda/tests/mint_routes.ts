@@ -0,0 +1,87 @@
+import {Connection, Keypair} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens, setMintRoutes} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+const forwardSol = true;
+
+describe("mint routes instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const tokenAmount = 1000;
+
+    let destination, routeDestination, quarantine, forwardAccount, authority, routedMint, otherMint, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        routeDestination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        await initialiseAccountWithMinimumBalance(routeDestination.publicKey, payer, connection);
+        routedMint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        otherMint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await setMintRoutes(forwardAccount.publicKey, authority, [{mint: routedMint, destination: routeDestination.publicKey}], program, payer, connection);
+    });
+
+    it("Should forward a routed mint to its route destination and other mints to the destination", async () => {
+        const routedForwardAta = await createAndFundAta(routedMint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const otherForwardAta = await createAndFundAta(otherMint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const routeDestinationAta = getAssociatedTokenAddressSync(routedMint, routeDestination.publicKey);
+        const destinationAta = getAssociatedTokenAddressSync(otherMint, destination.publicKey);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID,
+                routedMint, routedForwardAta, routeDestination.publicKey, routeDestinationAta,
+                otherMint, otherForwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(routeDestinationAta)).value.uiAmount, "route destination").to.equal(tokenAmount);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "destination").to.equal(tokenAmount);
+    });
+
+    it("Should not forward a routed mint to the destination", async () => {
+        const routedForwardAta = await createAndFundAta(routedMint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const destinationAta = getAssociatedTokenAddressSync(routedMint, destination.publicKey);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID,
+                routedMint, routedForwardAta, destination.publicKey, destinationAta);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x3")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not set duplicate mint routes", async () => {
+        try {
+            await setMintRoutes(forwardAccount.publicKey, authority, [
+                {mint: routedMint, destination: routeDestination.publicKey},
+                {mint: routedMint, destination: destination.publicKey}], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x14")
+            return;
+        }
+        expect.fail("Should not have set the mint routes")
+    });
+
+    it("Should not set the mint routes without the authority", async () => {
+        try {
+            await setMintRoutes(forwardAccount.publicKey, Keypair.generate(), [], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have set the mint routes")
+    });
+});
@@ -0,0 +1,100 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForwardWithNotBefore, deriveForwardPda, execute, setNotBefore} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Forward, NotBefore} from "./classes/classes";
+const forwardSol = true;
+
+describe("not before instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+    const oneDay = 24 * 60 * 60;
+
+    let destination, quarantine, forwardAccount, authority, forwardPda, forwardBump, notBefore;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        notBefore = new NotBefore({enum: 'unixTimestamp', unixTimestamp: Math.floor(Date.now() / 1000) + oneDay});
+        await createForwardWithNotBefore(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, notBefore, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+    });
+
+    it("Should create a forward with a time lock", async () => {
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(Number(fwd.notBefore.unixTimestamp)).to.equal(notBefore.unixTimestamp);
+    });
+
+    it("Should not execute a time locked forward", async () => {
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x18")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not close a time locked forward", async () => {
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, payer.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x18")
+            return;
+        }
+        expect.fail("Should not have closed")
+    });
+
+    it("Should execute once the time lock has passed", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setNotBefore(forwardAccount.publicKey, authority, new NotBefore({enum: 'unixTimestamp', unixTimestamp: Math.floor(Date.now() / 1000) - oneDay}), program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should execute once the time lock has been removed", async () => {
+        try {
+            await setNotBefore(forwardAccount.publicKey, authority, null, program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(fwd.notBefore).to.equal(null);
+    });
+
+    it("Should not extend the time lock", async () => {
+        try {
+            await setNotBefore(forwardAccount.publicKey, authority, new NotBefore({enum: 'unixTimestamp', unixTimestamp: notBefore.unixTimestamp + oneDay}), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x19")
+            return;
+        }
+        expect.fail("Should not have extended")
+    });
+
+    it("Should not shorten the time lock if not the authority", async () => {
+        try {
+            await setNotBefore(forwardAccount.publicKey, Keypair.generate(), null, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have shortened")
+    });
+});
@@ -0,0 +1,91 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForward, deriveForwardPda, execute, pause, quarantine as quarantineForward, unpause} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Forward} from "./classes/classes";
+const forwardSol = true;
+
+describe("pause instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+
+    let destination, quarantine, forwardAccount, authority, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should not execute a paused forward", async () => {
+        await pause(forwardAccount.publicKey, authority, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1c")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should execute once unpaused", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await pause(forwardAccount.publicKey, authority, program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await unpause(forwardAccount.publicKey, authority, program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(fwd.paused).to.equal(0);
+    });
+
+    it("Should not close a paused forward", async () => {
+        await pause(forwardAccount.publicKey, authority, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await closeForward(forwardPda, destination, forwardAccount.publicKey, authority, payer.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1c")
+            return;
+        }
+        expect.fail("Should not have closed")
+    });
+
+    it("Should quarantine a paused forward", async () => {
+        await pause(forwardAccount.publicKey, authority, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        const quarantineBalanceBefore = await connection.getBalance(quarantine.publicKey);
+        try {
+            await quarantineForward(true, forwardPda, quarantine.publicKey, forwardAccount.publicKey, authority, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have quarantined");
+        }
+        expect(await connection.getBalance(quarantine.publicKey) - quarantineBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should not pause if not the authority", async () => {
+        try {
+            await pause(forwardAccount.publicKey, Keypair.generate(), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have paused")
+    });
+});
@@ -0,0 +1,96 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit} from "./fns/accounts";
+import {createForward, deriveForwardPda, quarantine} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+
+const quarantineSol = true;
+
+describe("quarantine instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+
+    let destination, quarantineAccount, authority, forwardAccount, mint, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantineAccount = Keypair.generate();
+        authority = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantineAccount.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should move sol to the quarantine account", async () => {
+        let forwardAmount = LAMPORTS_PER_SOL / 100;
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await quarantine(quarantineSol, forwardPda, quarantineAccount.publicKey, forwardAccount.publicKey, authority, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have quarantined");
+        }
+        expect(await connection.getBalance(quarantineAccount.publicKey), "quarantine balance").to.equal(forwardAmount);
+        expect(await connection.getBalance(destination.publicKey), "destination balance").to.equal(0);
+    });
+
+    it("Should move tokens to the quarantine account", async () => {
+        let forwardAmount = 1000;
+        let forwardAta = await createAndFundAta(mint, forwardPda, forwardAmount, payer, mintAuthority, connection);
+        const quarantineAta = getAssociatedTokenAddressSync(mint, quarantineAccount.publicKey);
+        try {
+            await quarantine(!quarantineSol, forwardPda, quarantineAccount.publicKey, forwardAccount.publicKey, authority, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, quarantineAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have quarantined");
+        }
+        const info = await connection.getTokenAccountBalance(quarantineAta);
+        expect(info.value.uiAmount).to.equal(forwardAmount);
+    });
+
+    it("Should only quarantine the selected mints", async () => {
+        const mintAuthority2 = Keypair.generate();
+        let mint2 = await createMint(connection, payer, mintAuthority2.publicKey, null, 0);
+        let fwdAtaToken1 = await createAndFundAta(mint, forwardPda, 300, payer, mintAuthority, connection);
+        let fwdAtaToken2 = await createAndFundAta(mint2, forwardPda, 400, payer, mintAuthority2, connection);
+        const quarantineAta = getAssociatedTokenAddressSync(mint, quarantineAccount.publicKey);
+        try {
+            await quarantine(!quarantineSol, forwardPda, quarantineAccount.publicKey, forwardAccount.publicKey, authority, program, payer, connection, TOKEN_PROGRAM_ID, mint, fwdAtaToken1, quarantineAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have quarantined");
+        }
+        expect((await connection.getTokenAccountBalance(quarantineAta)).value.uiAmount, "token 1 balance").to.equal(300);
+        expect((await connection.getTokenAccountBalance(fwdAtaToken2)).value.uiAmount, "token 2 balance").to.equal(400);
+    });
+
+    it("Should not quarantine without the authority", async () => {
+        await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+        const bogusAuthority = Keypair.generate();
+        try {
+            await quarantine(quarantineSol, forwardPda, quarantineAccount.publicKey, forwardAccount.publicKey, bogusAuthority, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have quarantined")
+    });
+
+    it("Should not quarantine to an invalid quarantine account", async () => {
+        await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+        try {
+            await quarantine(quarantineSol, forwardPda, destination.publicKey, forwardAccount.publicKey, authority, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xe")
+            return;
+        }
+        expect.fail("Should not have quarantined")
+    });
+});
@@ -0,0 +1,132 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, executeWithTokens, setRateLimit} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Forward} from "./classes/classes";
+const forwardSol = true;
+
+describe("rate limit instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+    const oneDay = 24 * 60 * 60;
+
+    let destination, quarantine, forwardAccount, authority, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+    });
+
+    it("Should forward sol up to the lamport cap, leaving the rest for a later window", async () => {
+        const lamportCap = forwardAmount / 4;
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, oneDay, lamportCap, [], program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(lamportCap);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(Number(fwd.rateLimit.usedLamports)).to.equal(lamportCap);
+    });
+
+    it("Should leave the forward pda rent exempt when the lamport cap leaves less than the rent", async () => {
+        const lamportCap = forwardAmount - 1000;
+        const rentMinimum = await connection.getMinimumBalanceForRentExemption(0);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, oneDay, lamportCap, [], program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount - rentMinimum);
+        expect(await connection.getBalance(forwardPda)).to.equal(rentMinimum);
+    });
+
+    it("Should forward tokens up to the mint cap", async () => {
+        const tokenAmount = 1000;
+        const mintCap = 300;
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const destinationAta = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, oneDay, null, [{mint: mint, cap: mintCap}], program, payer, connection);
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(mintCap);
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount).to.equal(tokenAmount - mintCap);
+    });
+
+    it("Should start a new window once the window has elapsed", async () => {
+        const lamportCap = forwardAmount / 4;
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, 1, lamportCap, [], program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+            await new Promise(resolve => setTimeout(resolve, 2000));
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(2 * lamportCap);
+    });
+
+    it("Should forward everything once the rate limit is removed", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, oneDay, forwardAmount / 4, [], program, payer, connection);
+            await setRateLimit(forwardAccount.publicKey, authority, 0, null, [], program, payer, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(fwd.rateLimit).to.equal(null);
+    });
+
+    it("Should not set a rate limit without a window", async () => {
+        try {
+            await setRateLimit(forwardAccount.publicKey, authority, 0, forwardAmount, [], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1b")
+            return;
+        }
+        expect.fail("Should not have set")
+    });
+
+    it("Should not set the rate limit if not the authority", async () => {
+        try {
+            await setRateLimit(forwardAccount.publicKey, Keypair.generate(), oneDay, forwardAmount, [], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have set")
+    });
+});
@@ -0,0 +1,98 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, executeWithTokens} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+const forwardSol = true;
+
+describe("split instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+
+    let destination1, destination2, destination3, quarantine, forwardAccount, mint, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination1 = Keypair.generate();
+        destination2 = Keypair.generate();
+        destination3 = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination1.publicKey, payer, connection);
+        await initialiseAccountWithMinimumBalance(destination2.publicKey, payer, connection);
+        await initialiseAccountWithMinimumBalance(destination3.publicKey, payer, connection);
+        mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+    });
+
+    it("Should split sol across the recipients, giving the dust to the dust recipient", async () => {
+        const destinations = [destination1, destination2, destination3];
+        await createForward(forwardAccount, destinations.map(d => d.publicKey), quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, undefined, false, [3333, 3333, 3334], 1);
+        const balancesBefore = await Promise.all(destinations.map(d => connection.getBalance(d.publicKey)));
+        const forwardAmount = LAMPORTS_PER_SOL / 100 + 1;
+        try {
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destinations, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const balancesAfter = await Promise.all(destinations.map(d => connection.getBalance(d.publicKey)));
+        expect(balancesAfter[0] - balancesBefore[0], "destination 1").to.equal(3333000);
+        expect(balancesAfter[1] - balancesBefore[1], "destination 2").to.equal(3333001);
+        expect(balancesAfter[2] - balancesBefore[2], "destination 3").to.equal(3334000);
+    });
+
+    it("Should split tokens across the recipients", async () => {
+        const destinations = [destination1, destination2];
+        await createForward(forwardAccount, destinations.map(d => d.publicKey), quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, undefined, false, [7500, 2500]);
+        const forwardAta = await createAndFundAta(mint, forwardPda, 1001, payer, mintAuthority, connection);
+        const destinationAtas = destinations.map(d => getAssociatedTokenAddressSync(mint, d.publicKey));
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destinations, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, ...destinationAtas);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAtas[0])).value.uiAmount, "destination 1").to.equal(751);
+        expect((await connection.getTokenAccountBalance(destinationAtas[1])).value.uiAmount, "destination 2").to.equal(250);
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount, "forward").to.equal(0);
+    });
+
+    it("Should not create a forward when the bps do not add up to 100%", async () => {
+        try {
+            await createForward(forwardAccount, [destination1.publicKey, destination2.publicKey], quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, undefined, false, [5000, 4000]);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xf")
+            return;
+        }
+        expect.fail("Should not have created the forward")
+    });
+
+    it("Should not create a forward when the dust recipient is not a recipient", async () => {
+        try {
+            await createForward(forwardAccount, [destination1.publicKey, destination2.publicKey], quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, undefined, false, [5000, 5000], 2);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xf")
+            return;
+        }
+        expect.fail("Should not have created the forward")
+    });
+
+    it("Should not execute when the recipients are out of order", async () => {
+        await createForward(forwardAccount, [destination1.publicKey, destination2.publicKey], quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection, undefined, false, [5000, 5000]);
+        try {
+            await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+            await execute(forwardPda, [destination2, destination1], forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x3")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+});
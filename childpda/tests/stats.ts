@@ -0,0 +1,87 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, executeWithTokens, setBounty} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Bounty, Forward} from "./classes/classes";
+const forwardSol = true;
+
+describe("stats tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+
+    let destination, quarantine, forwardAccount, authority, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should record when the forward was created", async () => {
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(Number(fwd.stats.createdAt)).to.be.greaterThan(0);
+        expect(Number(fwd.stats.executionCount)).to.equal(0);
+        expect(Number(fwd.stats.lastExecutedAt)).to.equal(0);
+    });
+
+    it("Should record each execute and the sol it forwards", async () => {
+        try {
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(Number(fwd.stats.executionCount)).to.equal(2);
+        expect(Number(fwd.stats.lamportsForwarded)).to.equal(2 * forwardAmount);
+        expect(Number(fwd.stats.lastExecutedAt)).to.be.at.least(Number(fwd.stats.createdAt));
+    });
+
+    it("Should not count the bounty in the sol forwarded", async () => {
+        const bounty = 5000;
+        const keeper = Keypair.generate();
+        try {
+            await initialiseAccountWithMinimumBalance(keeper.publicKey, payer, connection);
+            await setBounty(forwardAccount.publicKey, authority, new Bounty({enum: 'lamports', lamports: bounty}), program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol, keeper);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(Number(fwd.stats.lamportsForwarded)).to.equal(forwardAmount - bounty);
+    });
+
+    it("Should total the tokens forwarded for each mint", async () => {
+        const tokenAmount = 1000;
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const destinationAta = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(fwd.stats.mintTotals.length).to.equal(1);
+        expect(Buffer.from(fwd.stats.mintTotals[0].mint).equals(mint.toBuffer())).to.equal(true);
+        expect(Number(fwd.stats.mintTotals[0].amount)).to.equal(tokenAmount);
+    });
+});
@@ -0,0 +1,119 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, executeWithTokens, setThresholds} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Forward} from "./classes/classes";
+const forwardSol = true;
+
+describe("thresholds instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const mintAuthority = Keypair.generate();
+    
+    let destination, quarantine, forwardAccount, authority, mint, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should store the thresholds", async () => {
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, 5000, [{mint: mint, amount: 100}], program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have set the thresholds");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardAccount.publicKey)).data);
+        expect(fwd.minLamports.toNumber(), "min lamports").to.equal(5000);
+        expect(fwd.mintThresholds.length, "mint thresholds").to.equal(1);
+        expect(fwd.mintThresholds[0].amount.toNumber(), "mint threshold").to.equal(100);
+    });
+
+    it("Should grow the forward account as thresholds are set", async () => {
+        const sizeBefore = (await connection.getAccountInfo(forwardAccount.publicKey)).data.length;
+        const payerBalanceBefore = await connection.getBalance(payer.publicKey);
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, 5000, [{mint: mint, amount: 100}, {mint: Keypair.generate().publicKey, amount: 200}], program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have set the thresholds");
+        }
+        const forwardInfo = await connection.getAccountInfo(forwardAccount.publicKey);
+        expect(forwardInfo.data.length, "forward size").to.equal(sizeBefore + 2 * (32 + 8));
+        expect(forwardInfo.lamports, "forward rent").to.equal(await connection.getMinimumBalanceForRentExemption(forwardInfo.data.length));
+        expect(await connection.getBalance(payer.publicKey), "payer balance").to.be.lessThan(payerBalanceBefore);
+    });
+
+    it("Should not forward sol below the threshold", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, LAMPORTS_PER_SOL / 10, [], program, payer, connection);
+            await deposit(payer, forwardPda, LAMPORTS_PER_SOL / 100, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey)).to.equal(destinationBalanceBefore);
+    });
+
+    it("Should forward sol once the threshold is reached", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        const forwardAmount = LAMPORTS_PER_SOL / 100;
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, forwardAmount, [], program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destination, forwardAccount.publicKey, program, payer, connection, forwardSol);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should not forward tokens below the mint threshold", async () => {
+        const forwardAta = await createAndFundAta(mint, forwardPda, 99, payer, mintAuthority, connection);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, 0, [{mint: mint, amount: 100}], program, payer, connection);
+            await executeWithTokens(forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount).to.equal(99);
+    });
+
+    it("Should not set duplicate mint thresholds", async () => {
+        try {
+            await setThresholds(forwardAccount.publicKey, authority, 0, [{mint: mint, amount: 100}, {mint: mint, amount: 200}], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x10")
+            return;
+        }
+        expect.fail("Should not have set the thresholds")
+    });
+
+    it("Should not set the thresholds without the authority", async () => {
+        try {
+            await setThresholds(forwardAccount.publicKey, Keypair.generate(), 0, [], program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x9")
+            return;
+        }
+        expect.fail("Should not have set the thresholds")
+    });
+});
@@ -0,0 +1,94 @@
+import {Connection, Keypair} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens, transferHookAccounts} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {
+    createInitializeTransferHookInstruction,
+    ExtensionType,
+    getAssociatedTokenAddressSync,
+    TOKEN_2022_PROGRAM_ID
+} from "@solana/spl-token";
+import {createAndFundAta, createMintWithExtensions} from "./fns/createToken";
+import {initialiseMockTransferHook, mockTransferHookCount} from "./fns/mockTransferHook";
+const forwardSol = true;
+
+describe("transfer hook tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward_childpda-keypair.json');
+    const hookProgram = createKeypairFromFile('../mockhook/program/target/so/mock_transfer_hook-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const tokenAmount = 1000;
+
+    let destination, quarantine, forwardAccount, mint, forwardPda, forwardBump, forwardAta, destinationAta;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        quarantine = Keypair.generate();
+        forwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(forwardAccount.publicKey, program.publicKey);
+        await createForward(forwardAccount, destination.publicKey, quarantine.publicKey, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferHook], mint =>
+            [createInitializeTransferHookInstruction(mint, mintAuthority.publicKey, hookProgram.publicKey, TOKEN_2022_PROGRAM_ID)]);
+        await initialiseMockTransferHook(mint, hookProgram.publicKey, payer, connection);
+        forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    });
+
+    it("Should forward tokens of a transfer hook mint", async () => {
+        const hookAccounts = await transferHookAccounts(connection, mint, forwardAta, destinationAta, forwardPda, tokenAmount);
+        expect(hookAccounts.length, "hook program, extra account metas and counter").to.equal(3);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta, ...hookAccounts);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "destination").to.equal(tokenAmount);
+        expect(await mockTransferHookCount(mint, hookProgram.publicKey, connection), "hook count").to.equal(tokenAmount);
+    });
+
+    it("Should not forward tokens of a transfer hook mint without the hook accounts", async () => {
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xa")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not forward tokens of a transfer hook mint with the wrong hook program", async () => {
+        const [, extraAccountMetas, ...extraAccounts] = await transferHookAccounts(connection, mint, forwardAta, destinationAta, forwardPda, tokenAmount);
+        try {
+            await executeWithTokens(!forwardSol, forwardPda, destination, forwardAccount.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta,
+                Keypair.generate().publicKey, extraAccountMetas, ...extraAccounts);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x15")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should not split tokens of a transfer hook mint across recipients", async () => {
+        const destination2 = Keypair.generate();
+        const splitForwardAccount = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination2.publicKey, payer, connection);
+        const [splitForwardPda, splitForwardBump] = deriveForwardPda(splitForwardAccount.publicKey, program.publicKey);
+        await createForward(splitForwardAccount, [destination.publicKey, destination2.publicKey], quarantine.publicKey, payer.publicKey, splitForwardBump, splitForwardPda, program, payer, connection, undefined, false, [5000, 5000]);
+        const splitForwardAta = await createAndFundAta(mint, splitForwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destination2Ata = getAssociatedTokenAddressSync(mint, destination2.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        const hookAccounts = await transferHookAccounts(connection, mint, splitForwardAta, destinationAta, splitForwardPda, tokenAmount);
+        try {
+            await executeWithTokens(!forwardSol, splitForwardPda, [destination, destination2], splitForwardAccount.publicKey, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, splitForwardAta, destinationAta, destination2Ata, ...hookAccounts);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x22")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+});
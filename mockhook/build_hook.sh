@@ -0,0 +1,10 @@
+#!/bin/bash
+
+set -x
+# Build and deploy the mock transfer hook program, used by the forward transfer hook tests.
+
+rm -r ./program/target
+cargo build-bpf --manifest-path=./program/Cargo.toml --bpf-out-dir=./program/target/so
+if [ $? -eq 0 ]; then
+    solana program deploy ./program/target/so/mock_transfer_hook.so
+fi
@@ -0,0 +1,108 @@
+use solana_program::{account_info::{AccountInfo, next_account_info}, entrypoint::ProgramResult, msg, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::{collect_extra_account_metas_signer_seeds, get_extra_account_metas_address_and_bump_seed};
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+
+//A transfer hook for the forward tests, it adds every transferred amount to a counter account derived from the mint,
+//the counter is the only extra account of the mint
+pub const COUNTER_SEED: &[u8] = b"counter";
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+
+    match TransferHookInstruction::unpack(instruction_data)? {
+        TransferHookInstruction::Execute { amount } => { execute(program_id, accounts, instruction_data, amount)}
+        TransferHookInstruction::InitializeExtraAccountMetaList { .. } => { initialize(program_id, accounts)}
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/**
+ * Create the extra account metas account and the counter account of the mint, the extra account metas passed in are ignored
+ *
+ *  - accounts[0] The extra account metas account
+ *  - accounts[1] The mint account
+ *  - accounts[2] The payer (signer)
+ *  - accounts[3] The system program account
+ *  - accounts[4] The counter account
+ */
+fn initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+
+    msg!("Initialize mock transfer hook");
+    let accounts_iter = &mut accounts.iter();
+    let extra_account_metas = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let counter = next_account_info(accounts_iter)?;
+
+    let (extra_account_metas_address, extra_account_metas_bump) = get_extra_account_metas_address_and_bump_seed(mint.key, program_id);
+    let (counter_address, counter_bump) = Pubkey::find_program_address(&[COUNTER_SEED, mint.key.as_ref()], program_id);
+    if *extra_account_metas.key != extra_account_metas_address || *counter.key != counter_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let metas = [ExtraAccountMeta::new_with_seeds(&[
+        Seed::Literal { bytes: COUNTER_SEED.to_vec() },
+        Seed::AccountKey { index: 1 }], false, true)?];
+    let metas_len = ExtraAccountMetaList::size_of(metas.len())?;
+    create_account(program_id, payer, extra_account_metas, system_program, metas_len,
+                   &collect_extra_account_metas_signer_seeds(mint.key, &[extra_account_metas_bump]))?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut extra_account_metas.try_borrow_mut_data()?, &metas)?;
+
+    create_account(program_id, payer, counter, system_program, 8, &[COUNTER_SEED, mint.key.as_ref(), &[counter_bump]])
+}
+
+/**
+ * Add the transferred amount to the counter of the mint
+ *
+ *  - accounts[0] The source token account
+ *  - accounts[1] The mint account
+ *  - accounts[2] The destination token account
+ *  - accounts[3] The source authority
+ *  - accounts[4] The extra account metas account
+ *  - accounts[5] The counter account
+ */
+fn execute(program_id: &Pubkey, accounts: &[AccountInfo], instruction_data: &[u8], amount: u64) -> ProgramResult {
+
+    msg!("Execute mock transfer hook, amount {}", amount);
+    let extra_account_metas = accounts.get(4).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let counter = accounts.get(5).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(accounts, instruction_data, program_id, &extra_account_metas.try_borrow_data()?)?;
+
+    let mut counter_data = counter.try_borrow_mut_data()?;
+    let total = u64::from_le_bytes(counter_data[..8].try_into().unwrap()).checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+    counter_data[..8].copy_from_slice(&total.to_le_bytes());
+    Ok(())
+}
+
+fn create_account<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(&system_instruction::create_account(
+        payer.key,
+        account.key,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        program_id,
+    ), &[
+        payer.clone(),
+        account.clone(),
+        system_program.clone(),
+    ], &[seeds])
+}
@@ -34,6 +34,72 @@ pub enum ForwardError {
 
     #[error("Underflow error")]
     UnderflowError,
+
+    #[error("Invalid Authority")]
+    InvalidAuthority,
+
+    #[error("Invalid split")]
+    InvalidSplit,
+
+    #[error("Invalid threshold")]
+    InvalidThreshold,
+
+    #[error("Invalid bounty")]
+    InvalidBounty,
+
+    #[error("Invalid mint policy")]
+    InvalidMintPolicy,
+
+    #[error("Mint not allowed")]
+    MintNotAllowed,
+
+    #[error("Invalid mint route")]
+    InvalidMintRoute,
+
+    #[error("Invalid seed")]
+    InvalidSeed,
+
+    #[error("Invalid transfer hook")]
+    InvalidTransferHook,
+
+    #[error("Invalid withdraw withheld authority")]
+    InvalidWithdrawWithheldAuthority,
+
+    #[error("Invalid stake account")]
+    InvalidStakeAccount,
+
+    #[error("Forward is time locked")]
+    ForwardTimeLocked,
+
+    #[error("Invalid time lock")]
+    InvalidTimeLock,
+
+    #[error("Invalid vesting")]
+    InvalidVesting,
+
+    #[error("Invalid rate limit")]
+    InvalidRateLimit,
+
+    #[error("Forward is paused")]
+    ForwardPaused,
+
+    #[error("Emergency stop is active")]
+    EmergencyStop,
+
+    #[error("Invalid config")]
+    InvalidConfig,
+
+    #[error("Too many mints")]
+    TooManyMints,
+
+    #[error("Invalid account type")]
+    InvalidAccountType,
+
+    #[error("Vesting already tracks the maximum number of mints")]
+    VestingMintsFull,
+
+    #[error("A transfer hook mint cannot be split across recipients")]
+    TransferHookSplit,
 }
 
 impl From<ForwardError> for ProgramError {
@@ -48,7 +114,6 @@ pub fn assert_that(requirement: &str, condition: bool, error: ProgramError) -> P
         Ok(())
     } else {
         msg!("{} - failed", requirement);
-        Err(error.into())
+        Err(error)
     }
 }
-
@@ -1,3 +1,17 @@
+pub mod authority;
+pub mod bounty;
+pub mod close;
+pub mod config;
 pub mod create;
+pub mod destination;
 pub mod execute;
-
+pub mod harvest;
+pub mod migrate;
+pub mod mint_policy;
+pub mod mint_routes;
+pub mod not_before;
+pub mod pause;
+pub mod rate_limit;
+pub mod stake;
+pub mod thresholds;
+pub mod vesting;
@@ -1,20 +1,68 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::{AccountInfo, next_account_info}, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
+use solana_program::{account_info::{AccountInfo, next_account_info}, clock::Clock, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar};
 use solana_program::program::invoke_signed;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use spl_token::state::Account as SplTokenAccount;
+use spl_token_2022::extension::StateWithExtensions;
 use spl_token_2022::state::Account as SplToken2022Account;
 
 use crate::errors::{assert_that, ForwardError};
-use crate::state::Forward;
+use crate::state::{Forward, NotBefore, Recipient, Stats};
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct CreateForwardInstruction {
+    seed: Vec<u8>,
+    bump: u8,
+    authority: Pubkey,
+    execute_requires_authority: bool,
+    recipient_bps: Vec<u16>,
+    dust_recipient: u8,
+    not_before: Option<NotBefore>,
+}
+
+//The original layout, a forward that only forwards to its destination
+//The u32 id is used as the seed in little endian so existing forward addresses are unchanged
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateForwardV1Instruction {
     id: u32,
     bump: u8,
 }
 
+impl CreateForwardV1Instruction {
+    //The original accounts, the forward pda, destination, payer and system program without the config account
+    pub const ACCOUNTS_LEN: usize = 4;
+}
+
+impl From<CreateForwardV1Instruction> for CreateForwardInstruction {
+    fn from(instr: CreateForwardV1Instruction) -> Self {
+        CreateForwardInstruction {
+            seed: instr.id.to_le_bytes().to_vec(),
+            bump: instr.bump,
+            authority: Pubkey::default(),
+            execute_requires_authority: false,
+            recipient_bps: vec![Recipient::TOTAL_BPS],
+            dust_recipient: 0,
+            not_before: None,
+        }
+    }
+}
+
+/**
+ * Create a forward, the forward pda is derived from the first destination and the seed
+ * A forward with a not before time lock cannot be executed or closed until it has passed
+ * The seed destination must sign unless the forward only forwards to it, with no authority, split or time lock
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward pda
+ *  - accounts[1] The destination account of the first recipient, used to derive the forward pda (signer, unless only forwarding to it)
+ *  - accounts[2] The payer (signer)
+ *  - accounts[3] The system program account
+ *  - accounts[4..] The destination account of each of the remaining recipients, in order, the split is signed for by the seed destination
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
 pub fn create(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -26,10 +74,11 @@ pub fn create(
     let destination_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
+    let destination_accounts = [destination_account].into_iter().chain(accounts_iter).collect::<Vec<_>>();
 
-    validate(program_id, system_account, forward_account, destination_account, &instr)
+    validate(program_id, system_account, forward_account, &destination_accounts, &instr)
         .and_then(|_|
-            create_forward_account(&program_id, &instr, &forward_account, &payer, &system_account, &destination_account.key))
+            create_forward_account(program_id, &instr, forward_account, payer, system_account, &destination_accounts))
 }
 
 fn create_forward_account<'a>(
@@ -38,32 +87,49 @@ fn create_forward_account<'a>(
     forward_account: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
     system_account: &AccountInfo<'a>,
-    destination_key: &Pubkey
+    destination_accounts: &[&AccountInfo<'a>],
 ) -> ProgramResult {
 
+    let seed_destination = destination_accounts[0].key;
+
+    let recipients = destination_accounts.iter()
+        .zip(instr.recipient_bps.iter())
+        .map(|(destination_account, bps)| Recipient::new(*destination_account.key, *bps))
+        .collect();
+
+    let forward = Forward {
+        not_before: instr.not_before,
+        stats: Stats::new(Clock::get()?.unix_timestamp),
+        ..Forward::new(
+            instr.seed.clone(),
+            *seed_destination,
+            instr.bump,
+            instr.authority,
+            instr.execute_requires_authority,
+            recipients,
+            instr.dust_recipient,
+        )
+    };
+
+    //Allocated for what is configured, setters grow the account as more is configured
+    let space = forward.space()?;
     invoke_signed(&system_instruction::create_account(
         payer.key,
         forward_account.key,
-        Rent::get()?.minimum_balance(Forward::LEN),
-        Forward::LEN.try_into().unwrap(),
+        Rent::get()?.minimum_balance(space),
+        space.try_into().unwrap(),
         program_id,
     ), &[
         payer.clone(),
         forward_account.clone(),
         system_account.clone(),
     ], &[&[
-        Forward::FORWARD_SEED.as_ref(),
-        destination_key.as_ref(),
-        instr.id.to_le_bytes().as_ref(),
+        Forward::FORWARD_SEED,
+        seed_destination.as_ref(),
+        instr.seed.as_ref(),
         &[instr.bump]]])?;
 
-    let forward = Forward::new(
-        instr.id,
-        destination_key.clone(),
-        instr.bump,
-    );
-
-    forward.serialize(&mut &mut forward_account.data.borrow_mut()[..])?;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
 
     Ok(())
 }
@@ -72,7 +138,7 @@ fn validate(
     program_id: &Pubkey,
     system_account: &AccountInfo,
     forward_account: &AccountInfo,
-    destination_account: &AccountInfo,
+    destination_accounts: &[&AccountInfo],
     instr: &CreateForwardInstruction
 ) -> ProgramResult {
 
@@ -83,16 +149,84 @@ fn validate(
                 forward_account.lamports() == 0 && Forward::try_from_slice(&forward_account.try_borrow_mut_data()?).is_err(),
                 ProgramError::from(ForwardError::ForwardAlreadyExists))?;
 
-    //TODO - is there a better way to do this?
-    assert_that("Destination is not an ATA",
-                SplToken2022Account::unpack(&destination_account.data.borrow()).is_err() && SplTokenAccount::unpack(&destination_account.data.borrow()).is_err(),
-            ProgramError::from(ForwardError::DestinationIsAnAta))?;
+    assert_that("Seed is valid",
+                !instr.seed.is_empty() && instr.seed.len() <= Forward::MAX_SEED_LEN,
+                ProgramError::from(ForwardError::InvalidSeed))?;
+
+    validate_recipients(destination_accounts, &instr.recipient_bps, instr.dust_recipient)?;
 
     let forward_pda_check =
-        Pubkey::create_program_address(&[Forward::FORWARD_SEED.as_ref(), destination_account.key.as_ref(), instr.id.to_le_bytes().as_ref(), &[instr.bump]], program_id);
+        Pubkey::create_program_address(&[Forward::FORWARD_SEED, destination_accounts[0].key.as_ref(), instr.seed.as_ref(), &[instr.bump]], program_id);
     assert_that("Forward address is valid",
                 forward_pda_check.is_ok() && forward_pda_check.unwrap() == *forward_account.key,
                 ProgramError::from(ForwardError::InvalidForwardAddress))?;
 
-    Ok(())
+    //Anyone can create the forward at the seed destination's address, so only a forward that just forwards to it is unsigned
+    assert_that("Seed destination is signer",
+                destination_accounts[0].is_signer || only_forwards_to_seed_destination(instr),
+                ProgramError::MissingRequiredSignature)
+}
+
+fn only_forwards_to_seed_destination(instr: &CreateForwardInstruction) -> bool {
+    instr.authority == Pubkey::default()
+        && !instr.execute_requires_authority
+        && instr.recipient_bps.len() == 1
+        && instr.not_before.is_none()
+}
+
+fn validate_recipients(
+    destination_accounts: &[&AccountInfo],
+    recipient_bps: &[u16],
+    dust_recipient: u8,
+) -> ProgramResult {
+
+    assert_that("Valid number of recipients",
+                !recipient_bps.is_empty() && recipient_bps.len() <= Recipient::MAX_RECIPIENTS && recipient_bps.len() == destination_accounts.len(),
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    assert_that("Recipient bps add up to 100%",
+                recipient_bps.iter().map(|bps| *bps as u32).sum::<u32>() == Recipient::TOTAL_BPS as u32,
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    assert_that("Dust recipient is a recipient",
+                (dust_recipient as usize) < recipient_bps.len(),
+                ProgramError::from(ForwardError::InvalidSplit))?;
+
+    destination_accounts.iter().try_for_each(|destination_account|
+        assert_not_an_ata("Destination is not an ATA", destination_account, ProgramError::from(ForwardError::DestinationIsAnAta)))
+}
+
+//TODO - is there a better way to do this?
+pub fn assert_not_an_ata(requirement: &str, account: &AccountInfo, error: ProgramError) -> ProgramResult {
+    assert_that(requirement,
+                StateWithExtensions::<SplToken2022Account>::unpack(&account.data.borrow()).is_err() && SplTokenAccount::unpack(&account.data.borrow()).is_err(),
+                error)
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::instructions::create::CreateForwardInstruction;
+    use crate::processor::ForwardInstruction;
+    use crate::state::Recipient;
+
+    #[test]
+    fn reads_the_original_create_layout() {
+        let mut data = vec![0];
+        data.extend_from_slice(&123456u32.to_le_bytes());
+        data.push(254);
+
+        let instr: CreateForwardInstruction = match ForwardInstruction::try_from_slice(&data).unwrap() {
+            ForwardInstruction::CreateForward(args) => args.into(),
+            _ => panic!("Should have read a create forward instruction"),
+        };
+        assert_eq!(instr.seed, 123456u32.to_le_bytes().to_vec());
+        assert_eq!(instr.bump, 254);
+        assert_eq!(instr.authority, Pubkey::default());
+        assert!(!instr.execute_requires_authority);
+        assert_eq!(instr.recipient_bps, vec![Recipient::TOTAL_BPS]);
+        assert!(instr.not_before.is_none());
+    }
 }
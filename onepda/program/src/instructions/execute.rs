@@ -1,23 +1,33 @@
 use std::slice::Iter;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::{AccountInfo, next_account_info};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
-use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::clock::Clock;
 use solana_program::rent::Rent;
+use solana_program::system_instruction;
 use solana_program::sysvar::Sysvar;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 use spl_token_2022::{check_spl_token_program_account, check_system_program_account};
-use spl_token_2022::instruction::transfer_checked;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions, transfer_hook};
+use spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee;
+use spl_token_2022::extension::transfer_fee::{TransferFeeAmount, TransferFeeConfig};
+use spl_token_2022::instruction::{close_account, sync_native, transfer_checked};
 use spl_token_2022::state::{Account, Mint};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use spl_transfer_hook_interface::onchain::add_extra_accounts_for_execute_cpi;
+use spl_type_length_value::state::TlvStateBorrowed;
 
 use crate::errors::{assert_that, ForwardError};
-use crate::state::Forward;
+use crate::instructions::stake::StakeAccounts;
+use crate::state::{Config, Forward, MintPolicy, RateLimit, Recipient, Stats, Vesting};
 
 #[macro_export]
 macro_rules! compute_fn {
@@ -31,176 +41,610 @@ macro_rules! compute_fn {
     };
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+pub struct ExecuteForwardInstruction {
+    close_emptied_atas: bool,
+    unwrap_native: bool,
+    stake_accounts: u8,
+}
+
 /**
- * Execute the forward instruction
+ * Execute the forward instruction, splitting sol and tokens across the recipients of the forward
+ * Sol and tokens held below the thresholds of the forward are left in the forward, mints not allowed by the mint policy are rejected
+ * If requested, the forward ATAs emptied by the execute are closed and their rent returned to the destination of the first recipient
+ * The forward wSOL ATA is synced before it is forwarded, if requested an unrouted wSOL ATA is instead closed to the forward and its lamports forwarded as sol
+ * Stake accounts the forward is the withdrawer of are swept into the forward before its sol is forwarded
+ * A paused forward fails to execute until it is unpaused
+ * A time locked forward fails to execute until its not before has passed
+ * A vesting forward only forwards the sol and tokens vested and not yet released, recording what it releases
+ * A vesting forward tracks at most 5 mints, other mints are left in the forward until the vesting ends
+ * A rate limited forward only forwards up to what is left of its caps in the current window, the rest is left for a later window
+ * The forward stats record each execute and the sol and tokens it forwards
+ * No more mints than the config maximum number of mints per execute can be forwarded by one execute
  *
  * @param program_id The program id
  * @param accounts The accounts to execute the instruction
  *  - accounts[0] The forward account
- *  - accounts[1] The destination account
+ *  - accounts[1..n] The destination account of each of the n recipients, in order
+ *  - If stake accounts are to be swept, the following 3 + s accounts are next, the rest of the accounts follow them
+ *      - The clock sysvar account
+ *      - The stake history sysvar account
+ *      - The stake program account
+ *      - Each of the s stake accounts
+ *  - accounts[n+1] The signer account (will pay for the destination atas to be created if they do not exist)
+ *      - Receives the forward bounty out of the forwarded sol
+ *      - Optional, unless tokens are to be forwarded or the forward requires the authority to execute
+ *      - Must be the forward authority if the forward requires the authority to execute
  *  - If tokens are to be forwarded, the following accounts are required
- *      - accounts[2] The signer account (will pay for the destination ata to be created if it does not exist)
- *      - accounts[3] The system program account
- *      - accounts[4] The token program account
- *      - accounts[5] The associated token program account
+ *      - accounts[n+2] The system program account
+ *      - accounts[n+3] The token program account
+ *      - accounts[n+4] The associated token program account
+ *
+ *      - Followed by the following 2 + n accounts for each mint/token to forward:
+ *      - The mint account
+ *      - The token program of the mint, only if the mint is owned by the other token program
+ *      - The forward ATA account
+ *      - The destination ATA account of each recipient, in order
+ *      - A mint with a route has its route destination account and route destination ATA in place of the recipient ATAs
+ *      - A mint with a transfer hook is then followed by the hook program, its extra account metas account and each extra account
+ *      - A mint with a transfer hook cannot be split across recipients, route it to a single destination instead
+ *
+ * @param config The program config
  *
- *      - Followed by the following 3 accounts for each mint/token to forward:
- *      - accounts[6] The mint account
- *      - accounts[7] The forward ATA account
- *      - accounts[8] The destination ATA account
  * @return Ok(()) if the instruction is executed successfully, otherwise an error
  */
-
 pub fn execute(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    args: ExecuteForwardInstruction,
+    config: &Config,
 ) -> ProgramResult {
 
     msg!("Executing forward instruction, accounts {}", accounts.len());
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+
+    let recipients = forward.recipients.len() + StakeAccounts::len(args.stake_accounts);
     assert_that("Valid number of accounts",
-                accounts.len() == 2 || (accounts.len() >= 9 && accounts.len() % 3 == 0),
+                accounts.len() == 1 + recipients || accounts.len() == 2 + recipients || accounts.len() >= 7 + recipients,
                 ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
 
-    let accounts_iter = &mut accounts.iter();
-    let forward_account = next_account_info(accounts_iter)?;
-    let destination_account = next_account_info(accounts_iter)?;
+    let destination_accounts = next_destination_accounts(&forward, accounts_iter)?;
+    let stake_accounts = StakeAccounts::read(args.stake_accounts, accounts_iter)?;
+    let signer = accounts_iter.next();
+    validate_signer(&forward, signer)?;
+    assert_that("Forward is not paused", !forward.paused, ProgramError::from(ForwardError::ForwardPaused))?;
+    validate_not_before(&forward)?;
 
-    let forward = validate_and_get_forward(program_id, &forward_account)?;
-    assert_that("Destination is valid", *destination_account.key == forward.destination, ProgramError::from(ForwardError::InvalidDestination))?;
+    if let Some(stake_accounts) = stake_accounts {
+        stake_accounts.sweep(&forward, forward_account)?;
+    }
 
-    maybe_forward_tokens(&&forward, forward_account, destination_account, accounts_iter)
+    let rent_recipient = destination_accounts.first().copied().filter(|_| args.close_emptied_atas);
+    let mut vesting = forward.vesting.clone();
+    let mut rate_limit = forward.rate_limit.clone();
+    let mut stats = forward.stats.clone();
+    if let Some(rate_limit) = rate_limit.as_mut() {
+        rate_limit.roll(&Clock::get()?);
+    }
+    maybe_forward_tokens(&forward, forward_account, &destination_accounts, signer, rent_recipient, args.unwrap_native, Some(config.max_mints_per_execute), vesting.as_mut(), rate_limit.as_mut(), Some(&mut stats), accounts_iter)
         .and_then(|_|
-            forward_sol(forward_account, destination_account))
+            forward_sol(&forward, forward_account, &destination_accounts, forward.min_lamports, vesting.as_mut(), rate_limit.as_mut(), Some(&mut stats), signer))?;
+
+    stats.record_execute(&Clock::get()?);
+    forward.vesting = vesting;
+    forward.rate_limit = rate_limit;
+    forward.stats = stats;
+    forward.pack(&mut forward_account.data.borrow_mut())?;
+    Ok(())
+}
+
+pub fn next_destination_accounts<'a, 'b>(
+    forward: &Forward,
+    accounts_iter: &mut Iter<'b, AccountInfo<'a>>,
+) -> Result<Vec<&'b AccountInfo<'a>>, ProgramError> {
+    forward.recipients.iter()
+        .map(|recipient| {
+            let destination_account = next_account_info(accounts_iter)?;
+            assert_that("Destination is valid", *destination_account.key == recipient.destination, ProgramError::from(ForwardError::InvalidDestination))?;
+            Ok(destination_account)
+        })
+        .collect()
+}
+
+/**
+ * The wallets, ATAs and split a mint is forwarded to
+ */
+pub struct TokenTargets<'a, 'b> {
+    pub accounts: Vec<&'b AccountInfo<'a>>,
+    pub atas: Vec<&'b AccountInfo<'a>>,
+    pub recipients: Vec<Recipient>,
+    pub dust_recipient: u8,
+}
+
+impl<'a, 'b> TokenTargets<'a, 'b> {
+
+    /**
+     * Read the ATA of each target account, the mint is split across the recipients
+     */
+    pub fn read<'c: 'b>(
+        accounts: Vec<&'b AccountInfo<'a>>,
+        recipients: Vec<Recipient>,
+        dust_recipient: u8,
+        accounts_iter: &mut Iter<'c, AccountInfo<'a>>,
+    ) -> Result<Self, ProgramError> {
+        let atas = accounts_iter.take(accounts.len()).collect::<Vec<_>>();
+        assert_that("Valid number of target ATAs", atas.len() == accounts.len(), ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
+        Ok(TokenTargets { accounts, atas, recipients, dust_recipient })
+    }
+}
+
+/**
+ * Read the target accounts of a mint, a routed mint is followed by its route destination and ATA,
+ * any other mint is followed by the ATA of each recipient and split across them
+ */
+pub fn next_token_targets<'a, 'b, 'c: 'b>(
+    forward: &Forward,
+    mint: &Pubkey,
+    destination_accounts: &[&'b AccountInfo<'a>],
+    accounts_iter: &mut Iter<'c, AccountInfo<'a>>,
+) -> Result<TokenTargets<'a, 'b>, ProgramError> {
+    match forward.mint_route(mint) {
+        Some(mint_route) => {
+            let route_destination = accounts_iter.next().ok_or(ForwardError::InvalidNumberOfAccounts)?;
+            assert_that("Route destination is valid", *route_destination.key == mint_route.destination, ProgramError::from(ForwardError::InvalidDestination))?;
+            TokenTargets::read(vec![route_destination], vec![Recipient::new(mint_route.destination, Recipient::TOTAL_BPS)], 0, accounts_iter)
+        }
+        None => TokenTargets::read(destination_accounts.to_vec(), forward.recipients.clone(), forward.dust_recipient, accounts_iter),
+    }
+}
+
+/**
+ * Read the token program of a mint, a mint owned by a different token program than the one of the instruction
+ * is followed by its own token program, so legacy and token 2022 mints can be forwarded together
+ */
+pub fn next_mint_token_program<'a>(
+    token_program: &AccountInfo<'a>,
+    mint_account: &AccountInfo<'a>,
+    accounts_iter: &mut Iter<AccountInfo<'a>>,
+) -> Result<AccountInfo<'a>, ProgramError> {
+    if mint_account.owner == token_program.key {
+        return Ok(token_program.clone());
+    }
+
+    let mint_token_program = accounts_iter.next().ok_or(ForwardError::InvalidNumberOfAccounts)?;
+    check_spl_token_program_account(mint_token_program.key)?;
+    assert_that("Token program owns the mint", mint_account.owner == mint_token_program.key, ProgramError::IncorrectProgramId)?;
+    Ok(mint_token_program.clone())
+}
+
+/**
+ * Read the transfer hook accounts of a mint, a mint with a transfer hook is followed by the hook program,
+ * the extra account metas account of the mint and each extra account it lists, in order
+ * One set of extra accounts is resolved per mint, so a mint with a transfer hook is only forwarded to a single target
+ */
+pub fn next_transfer_hook_accounts<'a>(
+    mint_account: &AccountInfo<'a>,
+    accounts_iter: &mut Iter<AccountInfo<'a>>,
+) -> Result<Vec<AccountInfo<'a>>, ProgramError> {
+    let hook_program_id = StateWithExtensions::<Mint>::unpack(&mint_account.data.borrow()).ok()
+        .and_then(|mint| transfer_hook::get_program_id(&mint));
+    let Some(hook_program_id) = hook_program_id else {
+        return Ok(Vec::new());
+    };
+
+    let hook_program = accounts_iter.next().ok_or(ForwardError::InvalidNumberOfAccounts)?;
+    let extra_account_metas = accounts_iter.next().ok_or(ForwardError::InvalidNumberOfAccounts)?;
+    assert_that("Transfer hook program is valid", *hook_program.key == hook_program_id, ProgramError::from(ForwardError::InvalidTransferHook))?;
+    assert_that("Extra account metas are valid for the mint",
+                *extra_account_metas.key == get_extra_account_metas_address(mint_account.key, &hook_program_id),
+                ProgramError::from(ForwardError::InvalidTransferHook))?;
 
+    let extra_accounts = {
+        let extra_account_metas_data = extra_account_metas.try_borrow_data()?;
+        let tlv_state = TlvStateBorrowed::unpack(&extra_account_metas_data)?;
+        ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?.data().len()
+    };
+    let hook_accounts = [hook_program, extra_account_metas].into_iter()
+        .chain(accounts_iter.take(extra_accounts))
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_that("Valid number of transfer hook accounts", hook_accounts.len() == 2 + extra_accounts, ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
+    Ok(hook_accounts)
 }
 
+/**
+ * A time locked forward cannot be executed or closed until its not before has passed
+ */
+pub fn validate_not_before(forward: &Forward) -> ProgramResult {
+    if let Some(not_before) = forward.not_before {
+        assert_that("Forward is not time locked", not_before.has_passed(&Clock::get()?), ProgramError::from(ForwardError::ForwardTimeLocked))?;
+    }
+    Ok(())
+}
+
+/**
+ * A vesting forward cannot be closed until its vesting has ended, as closing forwards everything it holds
+ */
+pub fn validate_vested(forward: &Forward) -> ProgramResult {
+    if let Some(vesting) = &forward.vesting {
+        assert_that("Forward has finished vesting", vesting.has_ended(&Clock::get()?), ProgramError::from(ForwardError::ForwardTimeLocked))?;
+    }
+    Ok(())
+}
+
+fn validate_signer(forward: &Forward, signer: Option<&AccountInfo>) -> ProgramResult {
+    if let Some(signer) = signer {
+        assert_that("Signer is signer", signer.is_signer, ProgramError::MissingRequiredSignature)?;
+    }
+    if forward.execute_requires_authority {
+        assert_that("Signer is the forward authority",
+                    signer.is_some_and(|signer| *signer.key == forward.authority),
+                    ProgramError::from(ForwardError::InvalidAuthority))?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn maybe_forward_tokens<'a>(
     forward: &Forward,
     forward_account: &AccountInfo<'a>,
-    target_account: &AccountInfo<'a>,
+    target_accounts: &[&AccountInfo<'a>],
+    signer: Option<&AccountInfo<'a>>,
+    rent_recipient: Option<&AccountInfo<'a>>,
+    unwrap_native: bool,
+    max_mints: Option<u8>,
+    vesting: Option<&mut Vesting>,
+    rate_limit: Option<&mut RateLimit>,
+    stats: Option<&mut Stats>,
     accounts_iter: &mut Iter<AccountInfo<'a>>,
 ) -> ProgramResult {
-    if let (Some(signer), Some(system_program), Some(token_program), Some(ata_token)) = (accounts_iter.next(), accounts_iter.next(), accounts_iter.next(), accounts_iter.next()) {
+    if let (Some(signer), Some(system_program), Some(token_program), Some(ata_token)) = (signer, accounts_iter.next(), accounts_iter.next(), accounts_iter.next()) {
         check_spl_token_program_account(token_program.key)?;
         check_system_program_account(system_program.key)?;
-        assert_that("Signer is signer", signer.is_signer, ProgramError::MissingRequiredSignature)?;
 
-        return forward_tokens(token_program, &forward, forward_account, target_account, signer, system_program, ata_token, accounts_iter);
+        return forward_tokens(token_program, forward, forward_account, target_accounts, signer, system_program, ata_token, rent_recipient, unwrap_native, max_mints, vesting, rate_limit, stats, accounts_iter);
     }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn forward_tokens<'a>(
     token_program: &AccountInfo<'a>,
     forward: &Forward,
     forward_account: &AccountInfo<'a>,
-    target_account: &AccountInfo<'a>,
+    target_accounts: &[&AccountInfo<'a>],
     signer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     ata_program: &AccountInfo<'a>,
+    rent_recipient: Option<&AccountInfo<'a>>,
+    unwrap_native: bool,
+    max_mints: Option<u8>,
+    mut vesting: Option<&mut Vesting>,
+    mut rate_limit: Option<&mut RateLimit>,
+    mut stats: Option<&mut Stats>,
     accounts_iter: &mut Iter<AccountInfo<'a>>,
 ) -> ProgramResult {
-    while let (Some(mint), Some(forward_ata), Some(target_ata)) = (accounts_iter.next(), accounts_iter.next(), accounts_iter.next()) {
-        forward_token(forward, token_program, mint, forward_account, target_account, forward_ata, target_ata, signer, system_program, ata_program)?;
+    let mut mints = 0;
+    while let Some(mint) = accounts_iter.next() {
+        mints += 1;
+        assert_that("Number of mints is within the maximum",
+                    !matches!(max_mints, Some(max_mints) if mints > max_mints as usize),
+                    ProgramError::from(ForwardError::TooManyMints))?;
+        let mint_token_program = next_mint_token_program(token_program, mint, accounts_iter)?;
+        let forward_ata = next_account_info(accounts_iter)?;
+        let targets = next_token_targets(forward, mint.key, target_accounts, accounts_iter)?;
+        let transfer_hook_accounts = next_transfer_hook_accounts(mint, accounts_iter)?;
+        if is_native_mint(mint.key) {
+            sync_native_ata(&mint_token_program, forward_ata)?;
+            if unwrap_native && forward.mint_route(mint.key).is_none() {
+                unwrap_forward_ata(forward, &mint_token_program, mint, forward_account, forward_ata)?;
+                continue;
+            }
+        }
+        forward_token(forward, &mint_token_program, mint, forward_account, forward_ata, &targets, &transfer_hook_accounts, signer, system_program, ata_program, forward.mint_threshold(mint.key), &forward.mint_policy, vesting.as_deref_mut(), rate_limit.as_deref_mut(), stats.as_deref_mut())?;
+        if let Some(rent_recipient) = rent_recipient {
+            if is_emptied(forward_ata)? {
+                close_forward_ata(forward, &mint_token_program, forward_account, forward_ata, rent_recipient)?;
+            }
+        }
     }
 
     Ok(())
 }
 
-fn forward_token<'a>(
+#[allow(clippy::too_many_arguments)]
+pub fn forward_token<'a>(
     forward: &Forward,
     token_program: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     forward_account: &AccountInfo<'a>,
-    target_account: &AccountInfo<'a>,
     forward_ata_account: &AccountInfo<'a>,
-    target_ata_account: &AccountInfo<'a>,
+    targets: &TokenTargets<'a, '_>,
+    transfer_hook_accounts: &[AccountInfo<'a>],
     signer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     ata_program: &AccountInfo<'a>,
+    threshold: u64,
+    mint_policy: &MintPolicy,
+    vesting: Option<&mut Vesting>,
+    rate_limit: Option<&mut RateLimit>,
+    stats: Option<&mut Stats>,
 ) -> ProgramResult {
     assert_that("Forward ATA matches forward",
-                *forward_ata_account.key == get_associated_token_address_with_program_id(&forward_account.key, mint_account.key, token_program.key),
+                *forward_ata_account.key == get_associated_token_address_with_program_id(forward_account.key, mint_account.key, token_program.key),
                 ProgramError::from(ForwardError::InvalidTokenSource))?;
 
-    assert_that("Target ATA matches forward",
-                *target_ata_account.key == get_associated_token_address_with_program_id(&target_account.key, mint_account.key, token_program.key),
+    assert_that("Target ATAs match targets",
+                targets.accounts.iter().zip(targets.atas.iter()).all(|(target_account, target_ata_account)|
+                    *target_ata_account.key == get_associated_token_address_with_program_id(target_account.key, mint_account.key, token_program.key)),
                 ProgramError::from(ForwardError::InvalidTokenDestination))?;
 
-    let forward_ata_state = Account::unpack(&forward_ata_account.data.borrow())?;
-    let token_balance = forward_ata_state.amount;
-    if token_balance == 0 {
+    assert_that("Mint is allowed", mint_policy.allows(mint_account.key), ProgramError::from(ForwardError::MintNotAllowed))?;
+
+    let token_balance = StateWithExtensions::<Account>::unpack(&forward_ata_account.data.borrow())?.base.amount;
+    if token_balance == 0 || token_balance < threshold {
         return Ok(());
     }
 
-    //Creates an associated token account for the given wallet address and token mint, if it doesn't already exist.
-    // Returns an error if the account exists, but with a different owner.
-    // [writeable,signer] Funding account (must be a system account)
-    // [writeable] Associated token account address to be created
-    // [] Wallet address for the new associated token account
-    // [] The token mint for the new associated token account
-    // [] System program
-    // [] SPL Token program
-    // [] ATA Token program <--- NOT IN THE DOCS!!!!
-
-    invoke(
-        &create_associated_token_account_idempotent(
-            signer.key,
-            target_account.key,
-            mint_account.key,
-            token_program.key,
-        ),
-        &[
-            signer.clone(),
-            target_ata_account.clone(),
-            target_account.clone(),
-            mint_account.clone(),
-            system_program.clone(),
-            token_program.clone(),
-            ata_program.clone(),
-        ], )?;
-
-    let mint = Mint::unpack(&mint_account.data.borrow())?;
+    let clock = Clock::get()?;
+    //Untracked mints are left in the forward until the vesting ends, so an airdrop of other mints cannot block execute
+    if vesting.as_ref().is_some_and(|vesting| !vesting.can_release_mint(mint_account.key) && !vesting.has_ended(&clock)) {
+        msg!("Vesting already tracks {} mints, skipping mint {}", Vesting::MAX_RELEASED_MINTS, mint_account.key);
+        return Ok(());
+    }
+    let amount = match &vesting {
+        Some(vesting) => vesting.releasable(token_balance, vesting.released_mint(mint_account.key), &clock)?,
+        None => token_balance,
+    };
+    let amount = rate_limit.as_ref().map_or(amount, |rate_limit| amount.min(rate_limit.mint_allowance(mint_account.key)));
+    if amount == 0 {
+        return Ok(());
+    }
+    //The hook extra accounts can depend on the destination and are only resolved for one target
+    assert_that("Transfer hook mint has a single target",
+                transfer_hook_accounts.is_empty() || targets.accounts.len() == 1,
+                ProgramError::from(ForwardError::TransferHookSplit))?;
+
+    let (decimals, transfer_fee_config) = {
+        let mint_data = mint_account.data.borrow();
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+        (mint.base.decimals, mint.get_extension::<TransferFeeConfig>().ok().copied())
+    };
+    let shares = Recipient::split(amount, &targets.recipients, targets.dust_recipient)?;
+    for ((target_account, target_ata_account), share) in targets.accounts.iter().zip(targets.atas.iter()).zip(shares) {
+        if share == 0 {
+            continue;
+        }
+
+        //Creates an associated token account for the given wallet address and token mint, if it doesn't already exist.
+        // Returns an error if the account exists, but with a different owner.
+        // [writeable,signer] Funding account (must be a system account)
+        // [writeable] Associated token account address to be created
+        // [] Wallet address for the new associated token account
+        // [] The token mint for the new associated token account
+        // [] System program
+        // [] SPL Token program
+        // [] ATA Token program <--- NOT IN THE DOCS!!!!
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                signer.key,
+                target_account.key,
+                mint_account.key,
+                token_program.key,
+            ),
+            &[
+                signer.clone(),
+                (*target_ata_account).clone(),
+                (*target_account).clone(),
+                mint_account.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                ata_program.clone(),
+            ], )?;
+
+        let fee = transfer_fee_config
+            .map(|transfer_fee_config| transfer_fee_config.calculate_epoch_fee(clock.epoch, share).ok_or(ForwardError::OverflowError))
+            .transpose()?;
+        transfer_share(
+            token_program,
+            forward_ata_account,
+            mint_account,
+            target_ata_account,
+            forward_account,
+            transfer_hook_accounts,
+            share,
+            decimals,
+            fee,
+            &[
+                Forward::FORWARD_SEED,
+                forward.seed_destination.as_ref(),
+                forward.seed.as_ref(),
+                &[forward.bump]])?;
+        if let Some(fee) = fee {
+            msg!("Transfer fee {}, forwarded {}", fee, share.checked_sub(fee).ok_or(ForwardError::UnderflowError)?);
+        }
+    }
+    if let Some(vesting) = vesting.filter(|vesting| vesting.can_release_mint(mint_account.key)) {
+        vesting.release_mint(mint_account.key, amount)?;
+    }
+    if let Some(rate_limit) = rate_limit {
+        rate_limit.use_mint(mint_account.key, amount)?;
+    }
+    if let Some(stats) = stats {
+        stats.record_mint(mint_account.key, amount);
+    }
+    Ok(())
+}
+
+/**
+ * Transfer checked from the forward ATA, a transfer fee mint transfers with its fee
+ * and a transfer hook mint passes the extra accounts of its hook
+ */
+#[allow(clippy::too_many_arguments)]
+fn transfer_share<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    transfer_hook_accounts: &[AccountInfo<'a>],
+    amount: u64,
+    decimals: u8,
+    fee: Option<u64>,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let mut transfer_instruction = match fee {
+        Some(fee) => transfer_checked_with_fee(token_program.key, source.key, mint.key, destination.key, authority.key, &[], amount, decimals, fee)?,
+        None => transfer_checked(token_program.key, source.key, mint.key, destination.key, authority.key, &[], amount, decimals)?,
+    };
+    let mut transfer_accounts = vec![source.clone(), mint.clone(), destination.clone(), authority.clone()];
+    if let Some(hook_program) = transfer_hook_accounts.first() {
+        add_extra_accounts_for_execute_cpi(&mut transfer_instruction, &mut transfer_accounts, hook_program.key,
+                                           source.clone(), mint.clone(), destination.clone(), authority.clone(), amount, transfer_hook_accounts)?;
+    }
+    invoke_signed(&transfer_instruction, &transfer_accounts, &[signer_seeds])
+}
+
+/**
+ * A wSOL mint of either token program
+ */
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    spl_token::native_mint::check_id(mint) || spl_token_2022::native_mint::check_id(mint)
+}
+
+/**
+ * Sync the forward wSOL ATA, so lamports sent directly to it are counted in its token balance
+ */
+fn sync_native_ata<'a>(token_program: &AccountInfo<'a>, forward_ata_account: &AccountInfo<'a>) -> ProgramResult {
+    invoke(&sync_native(token_program.key, forward_ata_account.key)?, &[forward_ata_account.clone(), token_program.clone()])
+}
+
+/**
+ * Unwrap the forward wSOL ATA, it is closed to the forward so its lamports are forwarded with the sol of the forward
+ */
+fn unwrap_forward_ata<'a>(
+    forward: &Forward,
+    token_program: &AccountInfo<'a>,
+    mint_account: &AccountInfo<'a>,
+    forward_account: &AccountInfo<'a>,
+    forward_ata_account: &AccountInfo<'a>,
+) -> ProgramResult {
+    assert_that("Forward ATA matches forward",
+                *forward_ata_account.key == get_associated_token_address_with_program_id(forward_account.key, mint_account.key, token_program.key),
+                ProgramError::from(ForwardError::InvalidTokenSource))?;
+    assert_that("Mint is allowed", forward.mint_policy.allows(mint_account.key), ProgramError::from(ForwardError::MintNotAllowed))?;
+    close_forward_ata(forward, token_program, forward_account, forward_ata_account, forward_account)
+}
+
+/**
+ * An emptied forward ATA holds no tokens and no withheld transfer fees, so it can be closed
+ */
+pub fn is_emptied(forward_ata_account: &AccountInfo) -> Result<bool, ProgramError> {
+    let forward_ata_data = forward_ata_account.data.borrow();
+    let forward_ata = StateWithExtensions::<Account>::unpack(&forward_ata_data)?;
+    let withheld_amount = forward_ata.get_extension::<TransferFeeAmount>().map_or(0, |transfer_fee_amount| u64::from(transfer_fee_amount.withheld_amount));
+    Ok(forward_ata.base.amount == 0 && withheld_amount == 0)
+}
+
+pub fn close_forward_ata<'a>(
+    forward: &Forward,
+    token_program: &AccountInfo<'a>,
+    forward_account: &AccountInfo<'a>,
+    forward_ata_account: &AccountInfo<'a>,
+    rent_recipient: &AccountInfo<'a>,
+) -> ProgramResult {
     invoke_signed(
-        &transfer_checked(
+        &close_account(
             token_program.key,
             forward_ata_account.key,
-            mint_account.key,
-            target_ata_account.key,
+            rent_recipient.key,
             forward_account.key,
             &[forward_account.key],
-            token_balance,
-            mint.decimals,
         )?,
         &[
             forward_ata_account.clone(),
-            mint_account.clone(),
-            target_ata_account.clone(),
+            rent_recipient.clone(),
             forward_account.clone(),
         ],
         &[&[
-            Forward::FORWARD_SEED.as_ref(),
-            forward.destination.as_ref(),
-            forward.id.to_le_bytes().as_ref(),
+            Forward::FORWARD_SEED,
+            forward.seed_destination.as_ref(),
+            forward.seed.as_ref(),
             &[forward.bump]]])
 }
 
-fn forward_sol(forward_account: &AccountInfo, destination_account: &AccountInfo) -> ProgramResult {
+#[allow(clippy::too_many_arguments)]
+pub fn forward_sol(
+    forward: &Forward,
+    forward_account: &AccountInfo,
+    destination_accounts: &[&AccountInfo],
+    threshold: u64,
+    vesting: Option<&mut Vesting>,
+    rate_limit: Option<&mut RateLimit>,
+    stats: Option<&mut Stats>,
+    bounty_recipient: Option<&AccountInfo>,
+) -> ProgramResult {
     compute_fn! { "onepda forward_sol" => {
         let rent_balance = Rent::get()?.minimum_balance(forward_account.data_len());
         let available_sol = forward_account.lamports().checked_sub(rent_balance).ok_or(ForwardError::UnderflowError)?;
+        let amount = match &vesting {
+            _ if available_sol < threshold => 0,
+            Some(vesting) => vesting.releasable(available_sol, vesting.released_lamports, &Clock::get()?)?,
+            None => available_sol,
+        };
+        let amount = rate_limit.as_ref().map_or(amount, |rate_limit| amount.min(rate_limit.lamport_allowance()));
 
-        if available_sol > 0 {
-            **forward_account.try_borrow_mut_lamports()? = rent_balance;
-            **destination_account.try_borrow_mut_lamports()? = destination_account.lamports().checked_add(available_sol).ok_or(ForwardError::OverflowError)?;
+        if amount > 0 {
+            let bounty = bounty_recipient.map_or(0, |_| forward.bounty.amount(amount));
+            let shares = Recipient::split(amount - bounty, &forward.recipients, forward.dust_recipient)?;
+            **forward_account.try_borrow_mut_lamports()? = forward_account.lamports().checked_sub(amount).ok_or(ForwardError::UnderflowError)?;
+            if let Some(bounty_recipient) = bounty_recipient {
+                **bounty_recipient.try_borrow_mut_lamports()? = bounty_recipient.lamports().checked_add(bounty).ok_or(ForwardError::OverflowError)?;
+            }
+            for (destination_account, share) in destination_accounts.iter().zip(shares) {
+                **destination_account.try_borrow_mut_lamports()? = destination_account.lamports().checked_add(share).ok_or(ForwardError::OverflowError)?;
+            }
+            if let Some(vesting) = vesting {
+                vesting.release_lamports(amount)?;
+            }
+            if let Some(rate_limit) = rate_limit {
+                rate_limit.use_lamports(amount)?;
+            }
+            if let Some(stats) = stats {
+                stats.record_lamports(amount - bounty);
+            }
         }
         Ok(())
     }}
 }
 
-fn validate_and_get_forward(program_id: &Pubkey, forward_account: &&AccountInfo) -> Result<Forward, ProgramError> {
+pub fn validate_and_get_forward(program_id: &Pubkey, forward_account: &&AccountInfo) -> Result<Forward, ProgramError> {
     assert_that("Forward account is owned by program", forward_account.owner == program_id, ProgramError::IncorrectProgramId)?;
-    Ok(Forward::try_from_slice(&forward_account.try_borrow_mut_data()?)?)
+    Forward::unpack(&forward_account.try_borrow_mut_data()?)
+}
+
+/**
+ * Pack the forward, first growing the forward account if what is configured on it no longer fits
+ * The payer tops up the rent for the added space, so deposits in the forward are not used for rent
+ */
+pub fn resize_and_pack<'a>(forward: &Forward, forward_account: &AccountInfo<'a>, payer: &AccountInfo<'a>, system_account: &AccountInfo<'a>) -> ProgramResult {
+    check_system_program_account(system_account.key)?;
+    assert_that("Payer is signer", payer.is_signer, ProgramError::MissingRequiredSignature)?;
+
+    let space = forward.space()?;
+    if space > forward_account.data_len() {
+        let rent = Rent::get()?;
+        let rent_top_up = rent.minimum_balance(space).saturating_sub(rent.minimum_balance(forward_account.data_len()));
+        if rent_top_up > 0 {
+            invoke(&system_instruction::transfer(payer.key, forward_account.key, rent_top_up),
+                   &[payer.clone(), forward_account.clone(), system_account.clone()])?;
+        }
+        forward_account.realloc(space, true)?;
+    }
+    forward.pack(&mut forward_account.data.borrow_mut())
 }
 
 
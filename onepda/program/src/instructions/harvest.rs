@@ -0,0 +1,97 @@
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::state::Mint;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::execute::validate_and_get_forward;
+use crate::state::Forward;
+
+/**
+ * Harvest the transfer fees withheld in the forward ATAs back into the forward ATAs, to be forwarded on the next execute
+ * The forward must be the withdraw withheld authority of each mint
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The token 2022 program account
+ *
+ *  - Followed by the following 2 accounts for each mint to harvest:
+ *  - accounts[2] The mint account
+ *  - accounts[3] The forward ATA account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn harvest_withheld_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    msg!("Harvest withheld fees instruction, accounts {}", accounts.len());
+    assert_that("Valid number of accounts",
+                accounts.len() >= 4 && accounts.len().is_multiple_of(2),
+                ProgramError::from(ForwardError::InvalidNumberOfAccounts))?;
+
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    let forward = validate_and_get_forward(program_id, &forward_account)?;
+    assert_that("Token program is token 2022", spl_token_2022::check_id(token_program.key), ProgramError::IncorrectProgramId)?;
+
+    while let Some(mint) = accounts_iter.next() {
+        let forward_ata = next_account_info(accounts_iter)?;
+        harvest_forward_ata(&forward, forward_account, token_program, mint, forward_ata)?;
+    }
+    Ok(())
+}
+
+fn harvest_forward_ata<'a>(
+    forward: &Forward,
+    forward_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    mint_account: &AccountInfo<'a>,
+    forward_ata_account: &AccountInfo<'a>,
+) -> ProgramResult {
+
+    assert_that("Forward ATA matches forward",
+                *forward_ata_account.key == get_associated_token_address_with_program_id(forward_account.key, mint_account.key, token_program.key),
+                ProgramError::from(ForwardError::InvalidTokenSource))?;
+
+    let withdraw_withheld_authority = {
+        let mint_data = mint_account.data.borrow();
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+        mint.get_extension::<TransferFeeConfig>().ok()
+            .and_then(|transfer_fee_config| Option::<Pubkey>::from(transfer_fee_config.withdraw_withheld_authority))
+    };
+    assert_that("Forward is the withdraw withheld authority",
+                withdraw_withheld_authority == Some(*forward_account.key),
+                ProgramError::from(ForwardError::InvalidWithdrawWithheldAuthority))?;
+
+    invoke_signed(
+        &withdraw_withheld_tokens_from_accounts(
+            token_program.key,
+            mint_account.key,
+            forward_ata_account.key,
+            forward_account.key,
+            &[],
+            &[forward_ata_account.key],
+        )?,
+        &[
+            mint_account.clone(),
+            forward_ata_account.clone(),
+            forward_account.clone(),
+        ],
+        &[&[
+            Forward::FORWARD_SEED,
+            forward.seed_destination.as_ref(),
+            forward.seed.as_ref(),
+            &[forward.bump]]])
+}
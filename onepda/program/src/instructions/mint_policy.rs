@@ -0,0 +1,54 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::MintPolicy;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetMintPolicyInstruction {
+    mint_policy: MintPolicy,
+}
+
+/**
+ * Set the mints the forward is allowed (or not allowed) to forward on execute
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_mint_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetMintPolicyInstruction,
+) -> ProgramResult {
+
+    msg!("Set mint policy instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    assert_that("Valid number of mints",
+                instr.mint_policy.mints().len() <= MintPolicy::MAX_MINTS,
+                ProgramError::from(ForwardError::InvalidMintPolicy))?;
+
+    forward.mint_policy = instr.mint_policy;
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::slice::Iter;
+
+use borsh::BorshDeserialize;
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::stake;
+use solana_program::stake::instruction::{deactivate_stake, withdraw};
+use solana_program::stake::state::StakeStateV2;
+use solana_program::stake_history::StakeHistory;
+use solana_program::sysvar::Sysvar;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::state::Forward;
+
+/**
+ * The stake accounts the forward is the withdrawer of, read ahead of the signer
+ *
+ *  - The clock sysvar account
+ *  - The stake history sysvar account
+ *  - The stake program account
+ *  - Followed by each stake account
+ */
+pub struct StakeAccounts<'a, 'b> {
+    pub clock_account: &'b AccountInfo<'a>,
+    pub stake_history_account: &'b AccountInfo<'a>,
+    pub accounts: Vec<&'b AccountInfo<'a>>,
+}
+
+impl<'a, 'b> StakeAccounts<'a, 'b> {
+
+    pub const SYSVAR_AND_PROGRAM_ACCOUNTS: usize = 3;
+
+    /**
+     * The number of accounts read for the given number of stake accounts
+     */
+    pub fn len(stake_accounts: u8) -> usize {
+        match stake_accounts {
+            0 => 0,
+            stake_accounts => Self::SYSVAR_AND_PROGRAM_ACCOUNTS + stake_accounts as usize,
+        }
+    }
+
+    pub fn read(stake_accounts: u8, accounts_iter: &mut Iter<'b, AccountInfo<'a>>) -> Result<Option<Self>, ProgramError> {
+        if stake_accounts == 0 {
+            return Ok(None);
+        }
+
+        let clock_account = next_account_info(accounts_iter)?;
+        let stake_history_account = next_account_info(accounts_iter)?;
+        let stake_program = next_account_info(accounts_iter)?;
+        assert_that("Stake program is valid", stake::program::check_id(stake_program.key), ProgramError::IncorrectProgramId)?;
+        let accounts = (0..stake_accounts).map(|_| next_account_info(accounts_iter)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(StakeAccounts { clock_account, stake_history_account, accounts }))
+    }
+
+    /**
+     * Sweep the stake accounts, inactive lamports are withdrawn to the forward and forwarded with its sol.
+     * Active stake is deactivated when the forward is also the staker, so it can be withdrawn in a later epoch
+     */
+    pub fn sweep(&self, forward: &Forward, forward_account: &AccountInfo<'a>) -> ProgramResult {
+        let clock = Clock::from_account_info(self.clock_account)?;
+        let stake_history = StakeHistory::from_account_info(self.stake_history_account)?;
+        for stake_account in &self.accounts {
+            sweep_stake_account(forward, forward_account, stake_account, self.clock_account, self.stake_history_account, &clock, &stake_history)?;
+        }
+        Ok(())
+    }
+}
+
+fn sweep_stake_account<'a>(
+    forward: &Forward,
+    forward_account: &AccountInfo<'a>,
+    stake_account: &AccountInfo<'a>,
+    clock_account: &AccountInfo<'a>,
+    stake_history_account: &AccountInfo<'a>,
+    clock: &Clock,
+    stake_history: &StakeHistory,
+) -> ProgramResult {
+    assert_that("Stake account is owned by the stake program", stake::program::check_id(stake_account.owner), ProgramError::from(ForwardError::InvalidStakeAccount))?;
+    let stake_state = StakeStateV2::deserialize(&mut &stake_account.data.borrow()[..])?;
+    let meta = stake_state.meta().ok_or(ForwardError::InvalidStakeAccount)?;
+    assert_that("Forward is the stake withdrawer", meta.authorized.withdrawer == *forward_account.key, ProgramError::from(ForwardError::InvalidStakeAccount))?;
+    if meta.lockup.is_in_force(clock, None) {
+        msg!("Stake account {} is locked up", stake_account.key);
+        return Ok(());
+    }
+
+    let signer_seeds: &[&[u8]] = &[
+        Forward::FORWARD_SEED,
+        forward.seed_destination.as_ref(),
+        forward.seed.as_ref(),
+        &[forward.bump]];
+
+    //The slower cooldown rate is assumed, so the withdrawal never exceeds what the stake program allows
+    let staked = match stake_state.delegation() {
+        None => 0,
+        Some(delegation) if delegation.deactivation_epoch == u64::MAX => {
+            if meta.authorized.staker == *forward_account.key {
+                invoke_signed(
+                    &deactivate_stake(stake_account.key, forward_account.key),
+                    &[stake_account.clone(), clock_account.clone(), forward_account.clone()],
+                    &[signer_seeds])?;
+            }
+            delegation.stake
+        }
+        Some(delegation) => delegation.stake(clock.epoch, stake_history, Some(0)),
+    };
+
+    let lamports = stake_account.lamports();
+    let withdrawable = match staked {
+        0 => lamports,
+        _ => lamports.saturating_sub(staked.checked_add(meta.rent_exempt_reserve).ok_or(ForwardError::OverflowError)?),
+    };
+    if withdrawable == 0 {
+        return Ok(());
+    }
+
+    msg!("Withdrawing {} from stake account {}", withdrawable, stake_account.key);
+    invoke_signed(
+        &withdraw(stake_account.key, forward_account.key, forward_account.key, withdrawable, None),
+        &[
+            stake_account.clone(),
+            forward_account.clone(),
+            clock_account.clone(),
+            stake_history_account.clone(),
+            forward_account.clone(),
+        ],
+        &[signer_seeds])
+}
@@ -0,0 +1,61 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::{AccountInfo, next_account_info};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::errors::{assert_that, ForwardError};
+use crate::instructions::authority::validate_authority;
+use crate::instructions::execute::{resize_and_pack, validate_and_get_forward};
+use crate::state::MintThreshold;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetThresholdsInstruction {
+    min_lamports: u64,
+    mint_thresholds: Vec<MintThreshold>,
+}
+
+/**
+ * Set the minimum balances the forward must hold before execute moves sol or a given token
+ *
+ * @param program_id The program id
+ * @param accounts The accounts to execute the instruction
+ *  - accounts[0] The forward account
+ *  - accounts[1] The forward authority (signer)
+ *  - accounts[2] The payer account (signer, pays the rent if the forward account grows)
+ *  - accounts[3] The system program account
+ *
+ * @return Ok(()) if the instruction is executed successfully, otherwise an error
+ */
+pub fn set_thresholds(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instr: SetThresholdsInstruction,
+) -> ProgramResult {
+
+    msg!("Set thresholds instruction");
+    let accounts_iter = &mut accounts.iter();
+    let forward_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    let mut forward = validate_and_get_forward(program_id, &forward_account)?;
+    validate_authority(&forward, authority)?;
+
+    assert_that("Valid number of mint thresholds",
+                instr.mint_thresholds.len() <= MintThreshold::MAX_MINT_THRESHOLDS,
+                ProgramError::from(ForwardError::InvalidThreshold))?;
+
+    assert_that("Mint thresholds are unique",
+                instr.mint_thresholds.iter().enumerate().all(|(i, mint_threshold)|
+                    instr.mint_thresholds[..i].iter().all(|other| other.mint != mint_threshold.mint)),
+                ProgramError::from(ForwardError::InvalidThreshold))?;
+
+    forward.min_lamports = instr.min_lamports;
+    forward.mint_thresholds = instr.mint_thresholds;
+    resize_and_pack(&forward, forward_account, payer, system_account)?;
+
+    Ok(())
+}
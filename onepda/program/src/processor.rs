@@ -1,13 +1,47 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::instructions::create::{create, CreateForwardInstruction};
-use crate::instructions::execute::execute;
+use crate::instructions::authority::{set_authority, SetAuthorityInstruction};
+use crate::instructions::bounty::{set_bounty, SetBountyInstruction};
+use crate::instructions::close::close;
+use crate::instructions::config::{init_config, split_config, update_config, InitConfigInstruction, UpdateConfigInstruction};
+use crate::instructions::create::{create, CreateForwardInstruction, CreateForwardV1Instruction};
+use crate::instructions::destination::{accept_destination, update_destination, UpdateDestinationInstruction};
+use crate::instructions::execute::{execute, ExecuteForwardInstruction};
+use crate::instructions::harvest::harvest_withheld_fees;
+use crate::instructions::migrate::migrate_forward;
+use crate::instructions::mint_policy::{set_mint_policy, SetMintPolicyInstruction};
+use crate::instructions::mint_routes::{set_mint_routes, SetMintRoutesInstruction};
+use crate::instructions::not_before::{set_not_before, SetNotBeforeInstruction};
+use crate::instructions::pause::{pause, unpause};
+use crate::instructions::rate_limit::{set_rate_limit, SetRateLimitInstruction};
+use crate::instructions::thresholds::{set_thresholds, SetThresholdsInstruction};
+use crate::instructions::vesting::{set_vesting, SetVestingInstruction};
+use crate::state::Config;
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum ForwardInstruction {
-    CreateForward(CreateForwardInstruction),
-    Execute
+    CreateForward(CreateForwardV1Instruction),
+    Execute,
+    SetAuthority(SetAuthorityInstruction),
+    CloseForward,
+    UpdateDestination(UpdateDestinationInstruction),
+    AcceptDestination,
+    SetThresholds(SetThresholdsInstruction),
+    SetBounty(SetBountyInstruction),
+    SetMintPolicy(SetMintPolicyInstruction),
+    SetMintRoutes(SetMintRoutesInstruction),
+    CreateForwardV2(CreateForwardInstruction),
+    HarvestWithheldFees,
+    ExecuteV2(ExecuteForwardInstruction),
+    SetNotBefore(SetNotBeforeInstruction),
+    SetVesting(SetVestingInstruction),
+    SetRateLimit(SetRateLimitInstruction),
+    Pause,
+    Unpause,
+    InitConfig(InitConfigInstruction),
+    UpdateConfig(UpdateConfigInstruction),
+    MigrateForward,
 }
 
 pub fn process_instruction(
@@ -19,7 +53,44 @@ pub fn process_instruction(
     let instruction = ForwardInstruction::try_from_slice(instruction_data)?;
 
     match instruction {
-        ForwardInstruction::CreateForward(args) => { create(program_id, accounts, args)}
-        ForwardInstruction::Execute => { execute(program_id, accounts)}
+        ForwardInstruction::InitConfig(args) => { init_config(program_id, accounts, args)}
+        ForwardInstruction::UpdateConfig(args) => { update_config(program_id, accounts, args)}
+        //The original create is sent without the config account, the forward it creates only forwards to its destination
+        ForwardInstruction::CreateForward(args) if accounts.len() == CreateForwardV1Instruction::ACCOUNTS_LEN => { create(program_id, accounts, args.into())}
+        instruction => {
+            let (config, accounts) = split_config(program_id, accounts)?;
+            process_forward_instruction(program_id, accounts, instruction, &config)
+        }
+    }
+}
+
+fn process_forward_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: ForwardInstruction,
+    config: &Config,
+) -> ProgramResult {
+
+    match instruction {
+        ForwardInstruction::CreateForward(args) => { create(program_id, accounts, args.into())}
+        ForwardInstruction::Execute => { execute(program_id, accounts, ExecuteForwardInstruction::default(), config)}
+        ForwardInstruction::SetAuthority(args) => { set_authority(program_id, accounts, args)}
+        ForwardInstruction::CloseForward => { close(program_id, accounts)}
+        ForwardInstruction::UpdateDestination(args) => { update_destination(program_id, accounts, args)}
+        ForwardInstruction::AcceptDestination => { accept_destination(program_id, accounts)}
+        ForwardInstruction::SetThresholds(args) => { set_thresholds(program_id, accounts, args)}
+        ForwardInstruction::SetBounty(args) => { set_bounty(program_id, accounts, args)}
+        ForwardInstruction::SetMintPolicy(args) => { set_mint_policy(program_id, accounts, args)}
+        ForwardInstruction::SetMintRoutes(args) => { set_mint_routes(program_id, accounts, args)}
+        ForwardInstruction::CreateForwardV2(args) => { create(program_id, accounts, args)}
+        ForwardInstruction::HarvestWithheldFees => { harvest_withheld_fees(program_id, accounts)}
+        ForwardInstruction::ExecuteV2(args) => { execute(program_id, accounts, args, config)}
+        ForwardInstruction::SetNotBefore(args) => { set_not_before(program_id, accounts, args)}
+        ForwardInstruction::SetVesting(args) => { set_vesting(program_id, accounts, args)}
+        ForwardInstruction::SetRateLimit(args) => { set_rate_limit(program_id, accounts, args)}
+        ForwardInstruction::Pause => { pause(program_id, accounts)}
+        ForwardInstruction::Unpause => { unpause(program_id, accounts)}
+        ForwardInstruction::MigrateForward => { migrate_forward(program_id, accounts)}
+        ForwardInstruction::InitConfig(_) | ForwardInstruction::UpdateConfig(_) => { Err(ProgramError::InvalidInstructionData)}
     }
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+
+use crate::errors::{assert_that, ForwardError};
+
+/**
+ * The type of a program owned account, prefixed to its state so one type of account cannot be loaded as another
+ */
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq)]
+pub enum AccountType {
+    Uninitialised,
+    Forward,
+    Config,
+}
+
+impl AccountType {
+
+    pub const LEN: usize = 1;
+
+    /**
+     * Checks the account data is prefixed with this account type
+     */
+    pub fn validate(&self, data: &[u8]) -> ProgramResult {
+        let account_type = data.first().and_then(|account_type| AccountType::try_from_slice(&[*account_type]).ok());
+        assert_that("Account type is valid", account_type == Some(*self), ProgramError::from(ForwardError::InvalidAccountType))
+    }
+}
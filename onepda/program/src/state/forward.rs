@@ -1,23 +1,217 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey;
 use solana_program::pubkey::Pubkey;
 
+use crate::state::{AccountType, Bounty, MintPolicy, MintRoute, MintThreshold, NotBefore, PendingDestination, RateLimit, Recipient, Stats, Vesting};
+
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub struct Forward {
-    pub id: u32,
-    pub destination: Pubkey,
+    pub account_type: AccountType,
+    pub version: u8,
+    pub seed: Vec<u8>,
+    pub seed_destination: Pubkey,
     pub bump: u8,
+    pub authority: Pubkey,
+    pub execute_requires_authority: bool,
+    pub recipients: Vec<Recipient>,
+    pub dust_recipient: u8,
+    pub pending_destination: Option<PendingDestination>,
+    pub min_lamports: u64,
+    pub mint_thresholds: Vec<MintThreshold>,
+    pub bounty: Bounty,
+    pub mint_policy: MintPolicy,
+    pub mint_routes: Vec<MintRoute>,
+    pub not_before: Option<NotBefore>,
+    pub vesting: Option<Vesting>,
+    pub rate_limit: Option<RateLimit>,
+    pub paused: bool,
+    pub stats: Stats,
 }
 
 impl Forward {
 
     pub const FORWARD_SEED: &'static[u8] = b"forward";
+    pub const MAX_SEED_LEN: usize = pubkey::MAX_SEED_LEN;
+    pub const VERSION: u8 = 1;
+
+    pub fn new(seed: Vec<u8>, seed_destination: Pubkey, bump: u8, authority: Pubkey, execute_requires_authority: bool, recipients: Vec<Recipient>, dust_recipient: u8) -> Self {
+        Forward {
+            account_type: AccountType::Forward,
+            version: Self::VERSION,
+            seed,
+            seed_destination,
+            bump,
+            authority,
+            execute_requires_authority,
+            recipients,
+            dust_recipient,
+            pending_destination: None,
+            min_lamports: 0,
+            mint_thresholds: Vec::new(),
+            bounty: Bounty::None,
+            mint_policy: MintPolicy::None,
+            mint_routes: Vec::new(),
+            not_before: None,
+            vesting: None,
+            rate_limit: None,
+            paused: false,
+            stats: Stats::default(),
+        }
+    }
+
+    /**
+     * The space the forward needs for what is configured on it, setters grow the account as more is configured
+     * The vesting and the stats grow on execute, so they take their maximum size
+     */
+    pub fn space(&self) -> Result<usize, ProgramError> {
+        let vesting_len = self.vesting.as_ref().map_or(Ok(0), borsh::object_length)?;
+        let stats_len = borsh::object_length(&self.stats)?;
+        Ok(borsh::object_length(self)? - vesting_len - stats_len + self.vesting.as_ref().map_or(0, |_| Vesting::LEN) + Stats::LEN)
+    }
+
+    /**
+     * Unpacks a forward of any known version, a forward of an older version keeps its version until it is migrated
+     * Forwards since the version byte are prefixed with their account type, which must be a forward
+     */
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() == ForwardV0::LEN {
+            return Ok(ForwardV0::try_from_slice(data)?.into());
+        }
+        AccountType::Forward.validate(data)?;
+        match data.get(AccountType::LEN) {
+            Some(&Self::VERSION) => Ok(Self::deserialize(&mut &data[..])?),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /**
+     * Packs a forward, an unversioned forward has nothing that can change so nothing is written
+     */
+    pub fn pack(&self, data: &mut [u8]) -> ProgramResult {
+        match self.version {
+            0 => {}
+            _ => self.serialize(&mut &mut data[..])?,
+        }
+        Ok(())
+    }
+
+    pub fn mint_route(&self, mint: &Pubkey) -> Option<&MintRoute> {
+        self.mint_routes.iter().find(|mint_route| mint_route.mint == *mint)
+    }
+
+    pub fn mint_threshold(&self, mint: &Pubkey) -> u64 {
+        self.mint_thresholds.iter()
+            .find(|mint_threshold| mint_threshold.mint == *mint)
+            .map_or(0, |mint_threshold| mint_threshold.amount)
+    }
+}
+
+/**
+ * The unversioned layout forwards were created with before the version byte, it has no authority so it can only be executed and migrated, its destination can sign the migration to become its authority
+ */
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct ForwardV0 {
+    pub id: u32,
+    pub destination: Pubkey,
+    pub bump: u8,
+}
+
+impl ForwardV0 {
     pub const LEN: usize = 4 + 32 + 1; //id + destination + bump
+}
 
-    pub fn new(id: u32, destination: Pubkey, bump: u8) -> Self {
+impl From<ForwardV0> for Forward {
+    fn from(forward: ForwardV0) -> Self {
         Forward {
-            id,
-            destination,
-            bump
+            version: 0,
+            ..Forward::new(forward.id.to_le_bytes().to_vec(), forward.destination, forward.bump, Pubkey::default(), false, vec![Recipient::new(forward.destination, Recipient::TOTAL_BPS)], 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::errors::ForwardError;
+    use crate::state::{AccountType, Forward, ForwardV0, Recipient, Stats, Vesting};
+
+    fn forward_v0(destination: Pubkey) -> Vec<u8> {
+        let data = borsh::to_vec(&ForwardV0 { id: 123456, destination, bump: 254 }).unwrap();
+        assert_eq!(data.len(), ForwardV0::LEN);
+        data
+    }
+
+    #[test]
+    fn unpacks_an_unversioned_forward() {
+        let destination = Pubkey::new_unique();
+        let forward = Forward::unpack(&forward_v0(destination)).unwrap();
+        assert_eq!(forward.version, 0);
+        assert_eq!(forward.seed, 123456u32.to_le_bytes().to_vec());
+        assert_eq!(forward.seed_destination, destination);
+        assert_eq!(forward.bump, 254);
+        assert_eq!(forward.authority, Pubkey::default());
+        assert_eq!(forward.recipients.len(), 1);
+        assert_eq!(forward.recipients[0].destination, destination);
+        assert_eq!(forward.recipients[0].bps, Recipient::TOTAL_BPS);
+    }
+
+    #[test]
+    fn packs_nothing_for_an_unversioned_forward() {
+        let data = forward_v0(Pubkey::new_unique());
+        let mut packed = data.clone();
+        Forward::unpack(&data).unwrap().pack(&mut packed).unwrap();
+        assert_eq!(packed, data);
+    }
+
+    #[test]
+    fn unpacks_a_packed_forward() {
+        let destination = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut forward = Forward::new(vec![1, 2, 3], destination, 253, authority, true, vec![Recipient::new(destination, Recipient::TOTAL_BPS)], 0);
+        forward.stats.record_lamports(1000);
+        let mut data = vec![0; forward.space().unwrap()];
+        forward.pack(&mut data).unwrap();
+
+        let unpacked = Forward::unpack(&data).unwrap();
+        assert_eq!(unpacked.version, Forward::VERSION);
+        assert_eq!(unpacked.seed, vec![1, 2, 3]);
+        assert_eq!(unpacked.authority, authority);
+        assert!(unpacked.execute_requires_authority);
+        assert_eq!(unpacked.stats.lamports_forwarded, 1000);
+    }
+
+    #[test]
+    fn does_not_unpack_another_account_type() {
+        let mut data = borsh::to_vec(&Forward::new(vec![], Pubkey::new_unique(), 255, Pubkey::new_unique(), false, vec![], 0)).unwrap();
+        data[0] = AccountType::Config as u8;
+        assert_eq!(Forward::unpack(&data).unwrap_err(), ProgramError::from(ForwardError::InvalidAccountType));
+    }
+
+    #[test]
+    fn does_not_unpack_an_unknown_version() {
+        let mut data = borsh::to_vec(&Forward::new(vec![], Pubkey::new_unique(), 255, Pubkey::new_unique(), false, vec![], 0)).unwrap();
+        data[AccountType::LEN] = Forward::VERSION + 1;
+        assert_eq!(Forward::unpack(&data).unwrap_err(), ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn sizes_the_vesting_and_stats_for_what_execute_records() {
+        let destination = Pubkey::new_unique();
+        let mut forward = Forward {
+            vesting: Some(Vesting::new(0, 100)),
+            ..Forward::new(vec![1, 2, 3], destination, 253, destination, false, vec![Recipient::new(destination, Recipient::TOTAL_BPS)], 0)
+        };
+        let space = forward.space().unwrap();
+
+        for _ in 0..Vesting::MAX_RELEASED_MINTS.max(Stats::MAX_MINT_TOTALS) {
+            let mint = Pubkey::new_unique();
+            let _ = forward.vesting.as_mut().unwrap().release_mint(&mint, 10);
+            forward.stats.record_mint(&mint, 10);
         }
+        assert_eq!(borsh::object_length(&forward).unwrap(), space);
     }
 }
@@ -0,0 +1,21 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MintRoute {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+}
+
+impl MintRoute {
+
+    pub const LEN: usize = 32 + 32; //mint + destination
+    pub const MAX_MINT_ROUTES: usize = 5;
+
+    pub fn new(mint: Pubkey, destination: Pubkey) -> Self {
+        MintRoute {
+            mint,
+            destination
+        }
+    }
+}
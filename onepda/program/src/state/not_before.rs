@@ -0,0 +1,34 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq)]
+pub enum NotBefore {
+    UnixTimestamp(i64),
+    Slot(u64),
+}
+
+impl NotBefore {
+
+    pub const LEN: usize = 1 + 8; //variant + largest value
+
+    /**
+     * Whether the time lock has passed at the given clock
+     */
+    pub fn has_passed(&self, clock: &Clock) -> bool {
+        match self {
+            NotBefore::UnixTimestamp(unix_timestamp) => clock.unix_timestamp >= *unix_timestamp,
+            NotBefore::Slot(slot) => clock.slot >= *slot,
+        }
+    }
+
+    /**
+     * Whether the time lock ends no later than the other, time locks of a different kind cannot be compared
+     */
+    pub fn is_no_later_than(&self, other: &NotBefore) -> bool {
+        match (self, other) {
+            (NotBefore::UnixTimestamp(unix_timestamp), NotBefore::UnixTimestamp(other)) => unix_timestamp <= other,
+            (NotBefore::Slot(slot), NotBefore::Slot(other)) => slot <= other,
+            _ => false,
+        }
+    }
+}
@@ -1,11 +1,12 @@
-import {Forward} from "./classes/classes";
+import {CreateForwardInstruction, Forward, ForwardInstructions} from "./classes/classes";
 import {expect} from "chai";
 import {Connection, Keypair, LAMPORTS_PER_SOL, PublicKey} from "@solana/web3.js";
 import {createKeypairFromFile} from "./fns/createKeyPair";
 import {beforeEach} from "mocha";
-import {createForward, deriveForwardPda} from "./fns/forwardFns";
+import {createForward, createForwardV1, deriveForwardPda} from "./fns/forwardFns";
 import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
 import {createAndFundAta} from "./fns/createToken";
+import {toLeArray} from "./fns/toLeArray";
 import {ASSOCIATED_TOKEN_PROGRAM_ID, createMint} from "@solana/spl-token";
 
 const connection = new Connection(`http://localhost:8899`, 'confirmed');
@@ -26,7 +27,7 @@ describe("create instruction tests", () => {
 
         [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
         try {
-            await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect.fail("Should have created forward");
             return;
@@ -35,21 +36,58 @@ describe("create instruction tests", () => {
         const forwardInfo = await connection.getAccountInfo(forwardPda);
         const fwd = Forward.fromBuffer(forwardInfo.data);
 
-        expect(fwd.id, "forward ID").to.equal(forwardId);
+        expect(Buffer.from(fwd.seed), "forward seed").to.deep.equal(Buffer.from(toLeArray(forwardId)));
         expect(fwd.bump, "forward bump").to.equal(forwardBump);
-        expect(new PublicKey(fwd.destination)).to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd.recipients[0].destination)).to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(payer.publicKey);
+        expect(fwd.executeRequiresAuthority, "execute requires authority").to.equal(0);
+    });
+
+    it("Should not create a forward with an authority without the destination's signature", async () => {
+        const attacker = Keypair.generate();
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        try {
+            await createForward(forwardId, destination.publicKey, attacker.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("missing required signature for instruction")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+
+    it("Should create a forward that only forwards to the destination without its signature", async () => {
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination.publicKey, PublicKey.default, forwardBump, forwardPda, program, payer, connection);
+
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(new PublicKey(fwd.recipients[0].destination)).to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(PublicKey.default);
+    });
+
+    it("Should create a forward from the original instruction layout and accounts", async () => {
+        const data = new CreateForwardInstruction({instruction: ForwardInstructions.CreateForward, id: forwardId, bump: 254}).toBuffer();
+        expect(data, "original layout").to.deep.equal(Buffer.from([0, ...toLeArray(forwardId), 254]));
+
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForwardV1(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(Buffer.from(fwd.seed), "forward seed").to.deep.equal(Buffer.from(toLeArray(forwardId)));
+        expect(fwd.recipients.length, "recipients").to.equal(1);
+        expect(new PublicKey(fwd.recipients[0].destination)).to.deep.equal(destination.publicKey);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(PublicKey.default);
     });
 
     it("Should not create forward if already exists", async () => {
         [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
         try {
-            await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect.fail("Should have created forward");
             return;
         }
         try {
-            await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x5")
             return;
@@ -62,8 +100,8 @@ describe("create instruction tests", () => {
         const [forwardPda1, forwardBump1] = deriveForwardPda(destination.publicKey, forwardId1, program.publicKey);
         const [forwardPda2, forwardBump2] = deriveForwardPda(destination.publicKey, forwardId2, program.publicKey);
         try {
-            await createForward(forwardId1, destination.publicKey, forwardBump1, forwardPda1, program, payer, connection);
-            await createForward(forwardId2, destination.publicKey, forwardBump2, forwardPda2, program, payer, connection);
+            await createForward(forwardId1, destination, payer.publicKey, forwardBump1, forwardPda1, program, payer, connection);
+            await createForward(forwardId2, destination, payer.publicKey, forwardBump2, forwardPda2, program, payer, connection);
         } catch (e) {
             console.log(e)
             expect.fail("Should have created forward");
@@ -73,8 +111,8 @@ describe("create instruction tests", () => {
         const forwardInfo2 = await connection.getAccountInfo(forwardPda2);
         const fwd1 = Forward.fromBuffer(forwardInfo1.data);
         const fwd2 = Forward.fromBuffer(forwardInfo2.data);
-        expect(fwd1.id).to.equal(forwardId1);
-        expect(fwd2.id).to.equal(forwardId2);
+        expect(Buffer.from(fwd1.seed)).to.deep.equal(Buffer.from(toLeArray(forwardId1)));
+        expect(Buffer.from(fwd2.seed)).to.deep.equal(Buffer.from(toLeArray(forwardId2)));
     });
 
     it("The destination account should not be an ATA", async () => {
@@ -83,7 +121,7 @@ describe("create instruction tests", () => {
         let destAtaToken1 = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection);
         const [forwardToTokenPda, forwardBump] = deriveForwardPda(destAtaToken1, forwardId, program.publicKey);
         try {
-            await createForward(forwardId, destAtaToken1, forwardBump, forwardToTokenPda, program, payer, connection);
+            await createForward(forwardId, destAtaToken1, payer.publicKey, forwardBump, forwardToTokenPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x1")
             return;
@@ -94,11 +132,11 @@ describe("create instruction tests", () => {
 
     it("Should fail to re-initialise the forward with a different destination", async () => {
         [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
-        await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         const bogusDestination = Keypair.generate();
         await initialiseAccountWithMinimumBalance(bogusDestination.publicKey, payer, connection);
         try {
-            await createForward(forwardId, bogusDestination.publicKey, forwardBump, forwardPda, program, payer, connection);
+            await createForward(forwardId, bogusDestination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x5")
             return;
@@ -109,7 +147,7 @@ describe("create instruction tests", () => {
     it("Should error if the forward pda does not match the derived pda", async () => {
         const bogusPda = Keypair.generate();
         try {
-            await createForward(forwardId, destination.publicKey, forwardBump, bogusPda.publicKey, program, payer, connection);
+            await createForward(forwardId, destination, payer.publicKey, forwardBump, bogusPda.publicKey, program, payer, connection);
         } catch (e) {
             expect(e.message).to.contain("custom program error: 0x6");
             return;
@@ -121,7 +159,7 @@ describe("create instruction tests", () => {
         // noinspection UnnecessaryLocalVariableJS
         const bogusSystemProgram = ASSOCIATED_TOKEN_PROGRAM_ID;
         try {
-            await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection, bogusSystemProgram);
+            await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection, bogusSystemProgram);
         } catch (e) {
             expect(e.message).to.contain("incorrect program id for instruction")
             return;
@@ -5,7 +5,6 @@ import {createKeypairFromFile} from "./fns/createKeyPair";
 import {beforeEach} from "mocha";
 import {expect} from "chai";
 import {
-    ASSOCIATED_TOKEN_PROGRAM_ID,
     createMint,
     getAssociatedTokenAddressSync,
     TOKEN_2022_PROGRAM_ID,
@@ -28,7 +27,7 @@ describe("execute instruction tests", () => {
         await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
         mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
         [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
-        await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
     });
 
     it("Should transfer sol when executed", async () => {
@@ -57,25 +56,23 @@ describe("execute instruction tests", () => {
         expect(info.value.uiAmount).to.equal(forwardAmount);
     });
 
-    it.skip("Should transfer tokens using the token 2022 program", async() => {
+    it("Should transfer tokens using the token 2022 program", async() => {
 
+        const mint2022Token = await createMint(connection, payer, mintAuthority.publicKey, null, 0, Keypair.generate(),  undefined, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint2022Token, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
 
-        let token2022Address = await getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
-        console.log("token2022Address", token2022Address.toBase58())
-        // let destinationAta = await createAndFundAta(connection, payer, mint, mintAuthority, 0, destination.publicKey, TOKEN_2022_PROGRAM_ID);
-
-        // let forwardAmount = 1000;
-        // let forwardAta = await createAndFundAta(connection, payer, mint, mintAuthority, forwardAmount, forwardPda, TOKEN_2022_PROGRAM_ID);
-        // try {
-        //     await executeWithTokens(payer, program, connection, forwardPda, destination, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
-        // } catch (e) {
-        //     console.log(e)
-        // }
-        // const info = await connection.getTokenAccountBalance(destinationAta);
-        // expect(info.value.uiAmount).to.equal(forwardAmount);
+        let forwardAmount = 1000;
+        let forwardAta = await createAndFundAta(mint2022Token, forwardPda, forwardAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint2022Token, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e)
+            expect.fail("Should have executed");
+        }
+        const info = await connection.getTokenAccountBalance(destinationAta);
+        expect(info.value.uiAmount).to.equal(forwardAmount);
     });
 
-
     it("Execute will not transfer sol or tokens if there are no funds", async () => {
 
         let destinationBalanceBefore = await connection.getBalance(destination.publicKey);
@@ -140,7 +137,7 @@ describe("execute instruction tests", () => {
 
         const uninitialisedDestination = Keypair.generate();
         const [newForwardPda, newForwardBump] = deriveForwardPda(uninitialisedDestination.publicKey, forwardId, program.publicKey);
-        await createForward(forwardId, uninitialisedDestination.publicKey, newForwardBump, newForwardPda, program, payer, connection);
+        await createForward(forwardId, uninitialisedDestination, payer.publicKey, newForwardBump, newForwardPda, program, payer, connection);
 
         let forwardAmount = LAMPORTS_PER_SOL / 100;
         await deposit(payer, newForwardPda, forwardAmount, connection);
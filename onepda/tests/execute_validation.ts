@@ -24,7 +24,7 @@ describe("execute validation tests", () => {
 
         await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
         [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
-        await createForward(forwardId, destination.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
     });
 
     it("Should error if the token program id is incorrect", async () => {
@@ -1,38 +1,133 @@
 import {
+    Keypair,
     PublicKey,
     sendAndConfirmTransaction,
+    StakeProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_STAKE_HISTORY_PUBKEY,
     SystemProgram,
     Transaction,
     TransactionInstruction
 } from "@solana/web3.js";
 import {Buffer} from "buffer";
 import {toLeArray} from "./toLeArray";
-import {CreateForwardInstruction, ExecuteForwardInstruction, ForwardInstructions} from "../classes/classes";
-import {ASSOCIATED_TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {
+    AcceptDestinationInstruction,
+    Bounty,
+    CloseForwardInstruction,
+    CreateForwardInstruction,
+    CreateForwardV2Instruction,
+    ExecuteForwardInstruction,
+    ExecuteForwardV2Instruction,
+    ForwardInstructions,
+    HarvestWithheldFeesInstruction,
+    InitConfigInstruction,
+    MigrateForwardInstruction,
+    MintCap,
+    MintPolicy,
+    MintRoute,
+    MintThreshold,
+    NotBefore,
+    PauseInstruction,
+    SetAuthorityInstruction,
+    SetBountyInstruction,
+    SetMintPolicyInstruction,
+    SetMintRoutesInstruction,
+    SetNotBeforeInstruction,
+    SetRateLimitInstruction,
+    SetThresholdsInstruction,
+    SetVestingInstruction,
+    UnpauseInstruction,
+    UpdateConfigInstruction,
+    UpdateDestinationInstruction
+} from "../classes/classes";
+import {
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    createExecuteInstruction,
+    getExtraAccountMetaAddress,
+    getExtraAccountMetas,
+    getMint,
+    getTransferHook,
+    resolveExtraAccountMeta,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID
+} from "@solana/spl-token";
+
+//id can be a u32 id or a seed of up to 32 bytes
+export function deriveForwardPda(destPubkey: PublicKey, id: Number | Buffer, programId) {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("forward"), destPubkey.toBuffer(), id instanceof Buffer ? id : Buffer.from(toLeArray(id))],
+        programId,
+    )
+}
+
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
 
-export function deriveForwardPda(destPubkey: PublicKey, id: Number, programId) {
+export function deriveConfigPda(programId) {
     return PublicKey.findProgramAddressSync(
-        [Buffer.from("forward"), destPubkey.toBuffer(), Buffer.from(toLeArray(id))],
+        [Buffer.from("config")],
         programId,
     )
 }
 
+//every instruction other than the config instructions is followed by the config account
+function forwardInstruction({keys, programId, data}) {
+    return new TransactionInstruction({
+        keys: [
+            ...keys,
+            {pubkey: deriveConfigPda(programId)[0], isSigner: false, isWritable: false},
+        ],
+        programId: programId,
+        data: data,
+    });
+}
+
+//destinations can be a single destination or one per recipient, in order
+function destinationKeys(destinations, isWritable = true) {
+    return [destinations].flat().map(destination =>
+        ({pubkey: destination instanceof PublicKey ? destination : destination.publicKey, isSigner: false, isWritable: isWritable}));
+}
+
+//a mint of the other token program is followed by that token program, which is read only
+function tokenKeys(tokenAccounts: PublicKey[]) {
+    return tokenAccounts.map(key =>
+        ({pubkey: key, isSigner: false, isWritable: !key.equals(TOKEN_PROGRAM_ID) && !key.equals(TOKEN_2022_PROGRAM_ID)}));
+}
+
 export async function createForward(
     forwardId,
-    desintationPublicKey: PublicKey,
+    destinations: Keypair | PublicKey | (Keypair | PublicKey)[],
+    authorityPublicKey: PublicKey,
     forwardBump,
     forwardPda,
     program,
     payer,
     connection,
-    systemProgram: any = SystemProgram.programId) {
+    systemProgram: any = SystemProgram.programId,
+    executeRequiresAuthority = false,
+    recipientBps: number[] = [10000],
+    dustRecipient = 0) {
+
+    await createForwardWithSeed(Buffer.from(toLeArray(forwardId)), destinations, authorityPublicKey, forwardBump, forwardPda, program, payer, connection,
+        systemProgram, executeRequiresAuthority, recipientBps, dustRecipient);
+}
+
+//the original create instruction and accounts, without the config account
+export async function createForwardV1(
+    forwardId,
+    destinationPublicKey: PublicKey,
+    forwardBump,
+    forwardPda,
+    program,
+    payer,
+    connection) {
 
     let ix = new TransactionInstruction({
         keys: [
             {pubkey: forwardPda, isSigner: false, isWritable: true},
-            {pubkey: desintationPublicKey, isSigner: false, isWritable: false},
+            {pubkey: destinationPublicKey, isSigner: false, isWritable: false},
             {pubkey: payer.publicKey, isSigner: true, isWritable: true},
-            {pubkey: systemProgram, isSigner: false, isWritable: false}
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false}
         ],
         programId: program.publicKey,
         data: (
@@ -50,20 +145,106 @@ export async function createForward(
     );
 }
 
-export async function execute(forwardPda, destination, program, payer, connection) {
+export async function createForwardWithSeed(
+    seed: Buffer,
+    destinations: Keypair | PublicKey | (Keypair | PublicKey)[],
+    authorityPublicKey: PublicKey,
+    forwardBump,
+    forwardPda,
+    program,
+    payer,
+    connection,
+    systemProgram: any = SystemProgram.programId,
+    executeRequiresAuthority = false,
+    recipientBps: number[] = [10000],
+    dustRecipient = 0,
+    notBefore: NotBefore = null) {
+
+    const data = new CreateForwardV2Instruction({
+        instruction: ForwardInstructions.CreateForwardV2,
+        seed: seed,
+        bump: forwardBump,
+        authority: authorityPublicKey.toBuffer(),
+        executeRequiresAuthority: executeRequiresAuthority ? 1 : 0,
+        recipientBps: recipientBps,
+        dustRecipient: dustRecipient,
+        notBefore: notBefore
+    }).toBuffer();
+    await sendCreateForward(data, destinations, forwardPda, program, payer, connection, systemProgram);
+}
 
-    let ix = new TransactionInstruction({
+//the seed destination signs when it is given as a keypair
+async function sendCreateForward(data: Buffer, destinations: Keypair | PublicKey | (Keypair | PublicKey)[], forwardPda, program, payer, connection, systemProgram) {
+    const seedDestination = [destinations].flat()[0];
+    const seedDestinationSigners = seedDestination instanceof PublicKey ? [] : [seedDestination];
+    let ix = forwardInstruction({
         keys: [
             {pubkey: forwardPda, isSigner: false, isWritable: true},
-            {pubkey: destination.publicKey, isSigner: false, isWritable: true},
+            {...destinationKeys(seedDestination, false)[0], isSigner: seedDestinationSigners.length > 0},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: systemProgram, isSigner: false, isWritable: false},
+            ...destinationKeys(destinations, false).slice(1),
         ],
         programId: program.publicKey,
+        data: data,
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, ...seedDestinationSigners]
+    );
+}
+
+export async function execute(forwardPda, destination, program, payer, connection, signer?) {
+
+    let keys = [
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+    ];
+    if (signer) {
+        keys.push({pubkey: signer.publicKey, isSigner: true, isWritable: true});
+    }
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
         data: (
             new ExecuteForwardInstruction({
                 instruction: ForwardInstructions.Execute,
             })
         ).toBuffer(),
     });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        signer ? [payer, signer] : [payer]
+    );
+}
+
+//sweeps the stake accounts the forward is the withdrawer of, forwarding their inactive lamports as sol
+export async function executeSweepingStake(forwardPda, destination, program, payer, connection, ...stakeAccounts: PublicKey[]) {
+
+    let keys = [
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+        {pubkey: SYSVAR_CLOCK_PUBKEY, isSigner: false, isWritable: false},
+        {pubkey: SYSVAR_STAKE_HISTORY_PUBKEY, isSigner: false, isWritable: false},
+        {pubkey: StakeProgram.programId, isSigner: false, isWritable: false},
+        ...stakeAccounts.map(key => ({pubkey: key, isSigner: false, isWritable: true})),
+    ];
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: (
+            new ExecuteForwardV2Instruction({
+                instruction: ForwardInstructions.ExecuteV2,
+                close_emptied_atas: 0,
+                unwrap_native: 0,
+                stake_accounts: stakeAccounts.length
+            })
+        ).toBuffer(),
+    });
     await sendAndConfirmTransaction(
         connection,
         new Transaction().add(ix),
@@ -71,13 +252,42 @@ export async function execute(forwardPda, destination, program, payer, connectio
     );
 }
 
-//tokenAccounts = mint1, forwardAta1, destinationAta1, mint2, forwardAta2, destinationAta2.....
+//tokenAccounts = mint1, forwardAta1, destinationAta1 (one per recipient), mint2, forwardAta2, destinationAta2.....
 export async function executeWithTokens(forwardPda, destination, program, payer, connection, token_program, ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardInstruction({
+        instruction: ForwardInstructions.Execute,
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, program, payer, connection, token_program, tokenAccounts);
+}
 
-    let tokenTransactionAccounts = tokenAccounts.map(key  => ({pubkey: key, isSigner: false, isWritable: true}));
+//closes the forward atas emptied by the execute, returning their rent to the destination of the first recipient
+export async function executeWithTokensClosingAtas(forwardPda, destination, program, payer, connection, token_program, ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardV2Instruction({
+        instruction: ForwardInstructions.ExecuteV2,
+        close_emptied_atas: 1,
+        unwrap_native: 0,
+        stake_accounts: 0
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, program, payer, connection, token_program, tokenAccounts);
+}
+
+//unwraps the forward wsol ata, forwarding its lamports as sol
+export async function executeWithTokensUnwrappingNative(forwardPda, destination, program, payer, connection, token_program, ...tokenAccounts: PublicKey[]) {
+    let data = new ExecuteForwardV2Instruction({
+        instruction: ForwardInstructions.ExecuteV2,
+        close_emptied_atas: 0,
+        unwrap_native: 1,
+        stake_accounts: 0
+    }).toBuffer();
+    await sendExecuteWithTokens(data, forwardPda, destination, program, payer, connection, token_program, tokenAccounts);
+}
+
+async function sendExecuteWithTokens(data: Buffer, forwardPda, destination, program, payer, connection, token_program, tokenAccounts: PublicKey[]) {
+
+    let tokenTransactionAccounts = tokenKeys(tokenAccounts);
     let keys = [
         {pubkey: forwardPda, isSigner: false, isWritable: true},
-        {pubkey: destination.publicKey, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
         {pubkey: payer.publicKey, isSigner: true, isWritable: true},
         {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
         {pubkey: token_program, isSigner: false, isWritable: false},
@@ -85,12 +295,531 @@ export async function executeWithTokens(forwardPda, destination, program, payer,
         ...tokenTransactionAccounts,
     ];
 
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: data,
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer]
+    );
+}
+
+
+export async function setAuthority(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    newAuthorityPublicKey: PublicKey,
+    executeRequiresAuthority: boolean,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: newAuthorityPublicKey, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetAuthorityInstruction({
+                instruction: ForwardInstructions.SetAuthority,
+                executeRequiresAuthority: executeRequiresAuthority ? 1 : 0
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//tokenAccounts = mint1, forwardAta1, destinationAta1 (one per recipient), mint2, forwardAta2, destinationAta2.....
+export async function closeForward(
+    forwardPda,
+    destination,
+    authority,
+    rentRecipient: PublicKey,
+    program,
+    payer,
+    connection,
+    token_program?: PublicKey,
+    ...tokenAccounts: PublicKey[]) {
+
+    let keys = [
+        {pubkey: forwardPda, isSigner: false, isWritable: true},
+        ...destinationKeys(destination),
+        {pubkey: authority.publicKey, isSigner: true, isWritable: true},
+        {pubkey: rentRecipient, isSigner: false, isWritable: true},
+    ];
+    if (token_program) {
+        keys.push(
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            {pubkey: token_program, isSigner: false, isWritable: false},
+            {pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+            ...tokenKeys(tokenAccounts),
+        );
+    }
+
+    let ix = forwardInstruction({
+        keys: keys,
+        programId: program.publicKey,
+        data: (
+            new CloseForwardInstruction({
+                instruction: ForwardInstructions.CloseForward,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function updateDestination(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    newDestinationPublicKey: PublicKey,
+    program,
+    payer,
+    connection,
+    index = 0) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            {pubkey: newDestinationPublicKey, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UpdateDestinationInstruction({
+                instruction: ForwardInstructions.UpdateDestination,
+                index: index
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function acceptDestination(
+    forwardAccountPublicKey: PublicKey,
+    newDestination,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: newDestination.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new AcceptDestinationInstruction({
+                instruction: ForwardInstructions.AcceptDestination,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, newDestination]
+    );
+}
+
+export async function setThresholds(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    minLamports: number,
+    mintThresholds: {mint: PublicKey, amount: number}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetThresholdsInstruction({
+                instruction: ForwardInstructions.SetThresholds,
+                minLamports: minLamports,
+                mintThresholds: mintThresholds.map(t => new MintThreshold({mint: t.mint.toBuffer(), amount: t.amount}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setBounty(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    bounty: Bounty,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetBountyInstruction({
+                instruction: ForwardInstructions.SetBounty,
+                bounty: bounty
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setMintPolicy(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    mintPolicy: MintPolicy,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetMintPolicyInstruction({
+                instruction: ForwardInstructions.SetMintPolicy,
+                mintPolicy: mintPolicy
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setMintRoutes(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    mintRoutes: {mint: PublicKey, destination: PublicKey}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            ...mintRoutes.map(r => ({pubkey: r.destination, isSigner: false, isWritable: false})),
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetMintRoutesInstruction({
+                instruction: ForwardInstructions.SetMintRoutes,
+                mintRoutes: mintRoutes.map(r => new MintRoute({mint: r.mint.toBuffer(), destination: r.destination.toBuffer()}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function setNotBefore(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    notBefore: NotBefore,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetNotBeforeInstruction({
+                instruction: ForwardInstructions.SetNotBefore,
+                notBefore: notBefore
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//start and end are unix timestamps
+export async function setVesting(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    start: number,
+    end: number,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetVestingInstruction({
+                instruction: ForwardInstructions.SetVesting,
+                start: start,
+                end: end
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//window is in seconds, a null lamport cap and no mint caps removes the rate limit
+export async function setRateLimit(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    window: number,
+    lamportCap: number | null,
+    mintCaps: {mint: PublicKey, cap: number}[],
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new SetRateLimitInstruction({
+                instruction: ForwardInstructions.SetRateLimit,
+                window: window,
+                lamportCap: lamportCap,
+                mintCaps: mintCaps.map(c => new MintCap({mint: c.mint.toBuffer(), cap: c.cap}))
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function pause(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new PauseInstruction({
+                instruction: ForwardInstructions.Pause,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+export async function unpause(
+    forwardAccountPublicKey: PublicKey,
+    authority,
+    program,
+    payer,
+    connection) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+            {pubkey: authority.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UnpauseInstruction({
+                instruction: ForwardInstructions.Unpause,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, authority]
+    );
+}
+
+//the admin must be the upgrade authority of the program
+export async function initConfig(
+    admin,
+    maxMintsPerExecute,
+    program,
+    payer,
+    connection,
+    [configPda, configBump] = deriveConfigPda(program.publicKey)) {
+
+    const [programData] = PublicKey.findProgramAddressSync([program.publicKey.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
     let ix = new TransactionInstruction({
+        keys: [
+            {pubkey: configPda, isSigner: false, isWritable: true},
+            {pubkey: admin.publicKey, isSigner: true, isWritable: true},
+            {pubkey: programData, isSigner: false, isWritable: false},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new InitConfigInstruction({
+                instruction: ForwardInstructions.InitConfig,
+                bump: configBump,
+                maxMintsPerExecute: maxMintsPerExecute,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, admin]
+    );
+}
+
+export async function updateConfig(
+    newAdminPublicKey: PublicKey,
+    emergencyStop: boolean,
+    maxMintsPerExecute,
+    admin,
+    program,
+    payer,
+    connection) {
+
+    let ix = new TransactionInstruction({
+        keys: [
+            {pubkey: deriveConfigPda(program.publicKey)[0], isSigner: false, isWritable: true},
+            {pubkey: admin.publicKey, isSigner: true, isWritable: false},
+        ],
+        programId: program.publicKey,
+        data: (
+            new UpdateConfigInstruction({
+                instruction: ForwardInstructions.UpdateConfig,
+                admin: newAdminPublicKey.toBuffer(),
+                emergencyStop: emergencyStop ? 1 : 0,
+                maxMintsPerExecute: maxMintsPerExecute,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer, admin]
+    );
+}
+
+//destination = the destination of an unversioned forward, signing to become its authority
+export async function migrateForward(
+    forwardAccountPublicKey: PublicKey,
+    program,
+    payer,
+    connection,
+    destination?) {
+
+    let keys = [
+        {pubkey: forwardAccountPublicKey, isSigner: false, isWritable: true},
+        {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+        {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+    ];
+    if (destination) {
+        keys.push({pubkey: destination.publicKey, isSigner: true, isWritable: false});
+    }
+
+    let ix = forwardInstruction({
         keys: keys,
         programId: program.publicKey,
         data: (
-            new ExecuteForwardInstruction({
-                instruction: ForwardInstructions.Execute,
+            new MigrateForwardInstruction({
+                instruction: ForwardInstructions.MigrateForward,
+            })
+        ).toBuffer(),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        destination ? [payer, destination] : [payer]
+    );
+}
+
+//mintAndForwardAtas is the mint followed by the forward ATA, for each mint to harvest
+export async function harvestWithheldFees(forwardPda, program, payer, connection, ...mintAndForwardAtas: PublicKey[]) {
+
+    let ix = forwardInstruction({
+        keys: [
+            {pubkey: forwardPda, isSigner: false, isWritable: true},
+            {pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false},
+            ...mintAndForwardAtas.map(key  => ({pubkey: key, isSigner: false, isWritable: true})),
+        ],
+        programId: program.publicKey,
+        data: (
+            new HarvestWithheldFeesInstruction({
+                instruction: ForwardInstructions.HarvestWithheldFees,
             })
         ).toBuffer(),
     });
@@ -101,3 +830,18 @@ export async function executeWithTokens(forwardPda, destination, program, payer,
     );
 }
 
+//The transfer hook accounts that follow the targets of a mint: the hook program, the extra account metas account and each extra account
+//The extra accounts are resolved for a transfer of the amount from the source ATA to the destination ATA, none are needed if the mint has no transfer hook
+export async function transferHookAccounts(connection, mint: PublicKey, source: PublicKey, destination: PublicKey, owner: PublicKey, amount, tokenProgram = TOKEN_2022_PROGRAM_ID) {
+    const transferHook = getTransferHook(await getMint(connection, mint, undefined, tokenProgram));
+    if (!transferHook || transferHook.programId.equals(PublicKey.default)) {
+        return [];
+    }
+    const extraAccountMetasAddress = getExtraAccountMetaAddress(mint, transferHook.programId);
+    const extraAccountMetas = getExtraAccountMetas(await connection.getAccountInfo(extraAccountMetasAddress));
+    const executeInstruction = createExecuteInstruction(transferHook.programId, source, mint, destination, owner, extraAccountMetasAddress, BigInt(amount));
+    for (const extraAccountMeta of extraAccountMetas) {
+        executeInstruction.keys.push(await resolveExtraAccountMeta(connection, extraAccountMeta, executeInstruction.keys, executeInstruction.data, transferHook.programId));
+    }
+    return [transferHook.programId, extraAccountMetasAddress, ...executeInstruction.keys.slice(5).map(meta => meta.pubkey)];
+}
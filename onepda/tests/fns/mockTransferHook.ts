@@ -0,0 +1,34 @@
+import {PublicKey, sendAndConfirmTransaction, SystemProgram, Transaction, TransactionInstruction} from "@solana/web3.js";
+import {Buffer} from "buffer";
+import {createHash} from "crypto";
+import {getExtraAccountMetaAddress} from "@solana/spl-token";
+
+export function deriveMockTransferHookCounter(mint: PublicKey, hookProgramId: PublicKey) {
+    return PublicKey.findProgramAddressSync([Buffer.from("counter"), mint.toBuffer()], hookProgramId)[0];
+}
+
+//Creates the extra account metas and counter of the mint, the mock hook sets its own extra account metas so none are passed
+export async function initialiseMockTransferHook(mint: PublicKey, hookProgramId: PublicKey, payer, connection) {
+    const initializeExtraAccountMetas = createHash('sha256').update('spl-transfer-hook-interface:initialize-extra-account-metas').digest().subarray(0, 8);
+    let ix = new TransactionInstruction({
+        keys: [
+            {pubkey: getExtraAccountMetaAddress(mint, hookProgramId), isSigner: false, isWritable: true},
+            {pubkey: mint, isSigner: false, isWritable: false},
+            {pubkey: payer.publicKey, isSigner: true, isWritable: true},
+            {pubkey: SystemProgram.programId, isSigner: false, isWritable: false},
+            {pubkey: deriveMockTransferHookCounter(mint, hookProgramId), isSigner: false, isWritable: true},
+        ],
+        programId: hookProgramId,
+        data: Buffer.concat([initializeExtraAccountMetas, Buffer.alloc(4)]),
+    });
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(ix),
+        [payer]
+    );
+}
+
+export async function mockTransferHookCount(mint: PublicKey, hookProgramId: PublicKey, connection) {
+    const counter = await connection.getAccountInfo(deriveMockTransferHookCounter(mint, hookProgramId));
+    return Number(counter.data.readBigUInt64LE(0));
+}
@@ -0,0 +1,84 @@
+import {Connection, Keypair} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens, harvestWithheldFees} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {
+    createInitializeTransferFeeConfigInstruction,
+    ExtensionType,
+    getAccount,
+    getAssociatedTokenAddressSync,
+    getTransferFeeAmount,
+    TOKEN_2022_PROGRAM_ID,
+    transferCheckedWithFee
+} from "@solana/spl-token";
+import {createAndFundAta, createMintWithExtensions} from "./fns/createToken";
+
+describe("harvest withheld fees instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardId = 123456;
+    const feeBps = 100;
+    const tokenAmount = 1000;
+    const fee = tokenAmount * feeBps / 10000;
+
+    let destination, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    async function depositWithFee(withdrawWithheldAuthority) {
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferFeeConfig], mint =>
+            [createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, withdrawWithheldAuthority, feeBps, BigInt(tokenAmount), TOKEN_2022_PROGRAM_ID)]);
+        const payerAta = await createAndFundAta(mint, payer.publicKey, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const forwardAta = await createAndFundAta(mint, forwardPda, 0, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        await transferCheckedWithFee(connection, payer, payerAta, mint, forwardAta, payer, BigInt(tokenAmount), 0, BigInt(fee), [], undefined, TOKEN_2022_PROGRAM_ID);
+        return [mint, forwardAta];
+    }
+
+    it("Should harvest withheld fees into the forward ATA", async () => {
+        const [mint, forwardAta] = await depositWithFee(forwardPda);
+        expect(Number(getTransferFeeAmount(await getAccount(connection, forwardAta, undefined, TOKEN_2022_PROGRAM_ID)).withheldAmount), "withheld before").to.equal(fee);
+        try {
+            await harvestWithheldFees(forwardPda, program, payer, connection, mint, forwardAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have harvested");
+        }
+        const forwardAtaState = await getAccount(connection, forwardAta, undefined, TOKEN_2022_PROGRAM_ID);
+        expect(Number(getTransferFeeAmount(forwardAtaState).withheldAmount), "withheld after").to.equal(0);
+        expect(Number(forwardAtaState.amount), "forward").to.equal(tokenAmount);
+    });
+
+    it("Should forward harvested fees less the transfer fee", async () => {
+        const [mint, forwardAta] = await depositWithFee(forwardPda);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        try {
+            await harvestWithheldFees(forwardPda, program, payer, connection, mint, forwardAta);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have forwarded");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(tokenAmount - fee);
+    });
+
+    it("Should not harvest if the forward is not the withdraw withheld authority", async () => {
+        const [mint, forwardAta] = await depositWithFee(mintAuthority.publicKey);
+        try {
+            await harvestWithheldFees(forwardPda, program, payer, connection, mint, forwardAta);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x13")
+            return;
+        }
+        expect.fail("Should not have harvested")
+    });
+});
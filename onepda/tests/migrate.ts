@@ -0,0 +1,69 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, execute, migrateForward} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Forward} from "./classes/classes";
+
+describe("migrate instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const forwardId = 123456;
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+    const currentVersion = 1;
+
+    let destination, authority, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should create a forward at the current version", async () => {
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(fwd.version).to.equal(currentVersion);
+    });
+
+    it("Should leave a forward at the current version unchanged", async () => {
+        const accountBefore = await connection.getAccountInfo(forwardPda);
+        try {
+            await migrateForward(forwardPda, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have migrated");
+        }
+        const accountAfter = await connection.getAccountInfo(forwardPda);
+        expect(accountAfter.data.equals(accountBefore.data)).to.equal(true);
+        expect(accountAfter.lamports).to.equal(accountBefore.lamports);
+    });
+
+    it("Should not change the authority of a versioned forward when its destination signs the migration", async () => {
+        try {
+            await migrateForward(forwardPda, program, payer, connection, destination);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have migrated");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(new PublicKey(fwd.authority), "authority").to.deep.equal(authority.publicKey);
+    });
+
+    it("Should execute a migrated forward", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await migrateForward(forwardPda, program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+});
@@ -0,0 +1,76 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForward, deriveForwardPda, execute, pause, unpause} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {Forward} from "./classes/classes";
+
+describe("pause instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const forwardId = 123456;
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+
+    let destination, authority, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should not execute a paused forward", async () => {
+        await pause(forwardPda, authority, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x19")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("Should execute once unpaused", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await pause(forwardPda, authority, program, payer, connection);
+            await deposit(payer, forwardPda, forwardAmount, connection);
+            await unpause(forwardPda, authority, program, payer, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(fwd.paused).to.equal(0);
+    });
+
+    it("Should not close a paused forward", async () => {
+        await pause(forwardPda, authority, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        try {
+            await closeForward(forwardPda, destination, authority, payer.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x19")
+            return;
+        }
+        expect.fail("Should not have closed")
+    });
+
+    it("Should not pause if not the authority", async () => {
+        try {
+            await pause(forwardPda, Keypair.generate(), program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xa")
+            return;
+        }
+        expect.fail("Should not have paused")
+    });
+});
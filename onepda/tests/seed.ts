@@ -0,0 +1,92 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, createForwardWithSeed, deriveForwardPda, execute, executeWithTokens} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {toLeArray} from "./fns/toLeArray";
+import {Forward} from "./classes/classes";
+
+describe("seed instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const seed = Buffer.from("invoices/2024");
+
+    let destination, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, seed, program.publicKey);
+    });
+
+    it("Should create a forward with a seed", async () => {
+        try {
+            await createForwardWithSeed(seed, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have created forward");
+        }
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(Buffer.from(fwd.seed), "forward seed").to.deep.equal(seed);
+        expect(fwd.bump, "forward bump").to.equal(forwardBump);
+    });
+
+    it("Should forward sol and tokens from a forward created with a seed", async () => {
+        const forwardAmount = LAMPORTS_PER_SOL / 100;
+        const tokenAmount = 1000;
+        await createForwardWithSeed(seed, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await execute(forwardPda, destination, program, payer, connection);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(tokenAmount);
+    });
+
+    it("Should derive the same forward from an id and its little endian seed", async () => {
+        const forwardId = 123456;
+        const [idPda, idBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, payer.publicKey, idBump, idPda, program, payer, connection);
+        try {
+            await createForwardWithSeed(Buffer.from(toLeArray(forwardId)), destination, payer.publicKey, idBump, idPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x5")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+
+    it("Should not create a forward with an empty seed", async () => {
+        try {
+            await createForwardWithSeed(Buffer.alloc(0), destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x11")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+
+    it("Should not create a forward with a seed longer than 32 bytes", async () => {
+        try {
+            await createForwardWithSeed(Buffer.alloc(33, 1), destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x11")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+});
@@ -0,0 +1,94 @@
+import {
+    Authorized,
+    Connection,
+    Keypair,
+    LAMPORTS_PER_SOL,
+    Lockup,
+    PublicKey,
+    sendAndConfirmTransaction,
+    StakeAuthorizationLayout,
+    StakeProgram,
+    Transaction
+} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeSweepingStake} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+
+describe("stake account tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const forwardId = 123456;
+    const stakeAmount = LAMPORTS_PER_SOL / 10;
+
+    let destination, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    async function createStakeAccount(staker: PublicKey, withdrawer: PublicKey) {
+        const stakeAccount = Keypair.generate();
+        const lamports = stakeAmount + await connection.getMinimumBalanceForRentExemption(StakeProgram.space);
+        await sendAndConfirmTransaction(connection, new Transaction().add(StakeProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            stakePubkey: stakeAccount.publicKey,
+            authorized: new Authorized(staker, withdrawer),
+            lockup: new Lockup(0, 0, PublicKey.default),
+            lamports: lamports,
+        })), [payer, stakeAccount]);
+        return stakeAccount.publicKey;
+    }
+
+    it("Should withdraw an undelegated stake account to the destination", async () => {
+        const stakeAccount = await createStakeAccount(payer.publicKey, forwardPda);
+        const stakeLamports = await connection.getBalance(stakeAccount);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await executeSweepingStake(forwardPda, destination, program, payer, connection, stakeAccount);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getAccountInfo(stakeAccount), "stake account").to.be.null;
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore, "destination").to.equal(stakeLamports);
+    });
+
+    it("Should deactivate active stake when the forward is the staker", async () => {
+        const stakeAccount = await createStakeAccount(payer.publicKey, forwardPda);
+        const votePubkey = new PublicKey((await connection.getVoteAccounts()).current[0].votePubkey);
+        await sendAndConfirmTransaction(connection, new Transaction()
+            .add(StakeProgram.delegate({stakePubkey: stakeAccount, authorizedPubkey: payer.publicKey, votePubkey: votePubkey}))
+            .add(StakeProgram.authorize({
+                stakePubkey: stakeAccount,
+                authorizedPubkey: payer.publicKey,
+                newAuthorizedPubkey: forwardPda,
+                stakeAuthorizationType: StakeAuthorizationLayout.Staker
+            })), [payer]);
+        try {
+            await executeSweepingStake(forwardPda, destination, program, payer, connection, stakeAccount);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const stakeInfo = (await connection.getParsedAccountInfo(stakeAccount)).value.data as any;
+        expect(stakeInfo.parsed.info.stake.delegation.deactivationEpoch, "deactivation epoch").to.not.equal("18446744073709551615");
+    });
+
+    it("Should not sweep a stake account the forward is not the withdrawer of", async () => {
+        const stakeAccount = await createStakeAccount(payer.publicKey, payer.publicKey);
+        try {
+            await executeSweepingStake(forwardPda, destination, program, payer, connection, stakeAccount);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x14")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+});
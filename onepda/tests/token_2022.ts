@@ -0,0 +1,129 @@
+import {Connection, Keypair} from '@solana/web3.js';
+import {initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {
+    createInitializeMetadataPointerInstruction,
+    createMint,
+    createInitializeTransferFeeConfigInstruction,
+    ExtensionType,
+    getAccount,
+    getAssociatedTokenAddressSync,
+    getImmutableOwner,
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID
+} from "@solana/spl-token";
+import {createAndFundAta, createMintWithExtensions} from "./fns/createToken";
+
+describe("token 2022 extension tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardId = 123456;
+    const tokenAmount = 1000;
+
+    let destination, forwardPda, forwardBump;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+    });
+
+    it("Should forward tokens of a transfer fee mint, less the fee", async () => {
+        const feeBps = 100;
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferFeeConfig], mint =>
+            [createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, mintAuthority.publicKey, feeBps, BigInt(tokenAmount), TOKEN_2022_PROGRAM_ID)]);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(forwardAta)).value.uiAmount, "forward").to.equal(0);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "destination").to.equal(tokenAmount - tokenAmount * feeBps / 10000);
+    });
+
+    it("Should forward tokens between immutable owner accounts", async () => {
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [], () => []);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        expect(getImmutableOwner(await getAccount(connection, forwardAta, undefined, TOKEN_2022_PROGRAM_ID)), "forward ata immutable owner").to.not.be.null;
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const destinationAtaState = await getAccount(connection, destinationAta, undefined, TOKEN_2022_PROGRAM_ID);
+        expect(getImmutableOwner(destinationAtaState), "destination ata immutable owner").to.not.be.null;
+        expect(Number(destinationAtaState.amount)).to.equal(tokenAmount);
+    });
+
+    it("Should forward tokens of a metadata pointer mint", async () => {
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.MetadataPointer], mint =>
+            [createInitializeMetadataPointerInstruction(mint, mintAuthority.publicKey, mint, TOKEN_2022_PROGRAM_ID)]);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_2022_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount).to.equal(tokenAmount);
+    });
+
+    it("Should forward legacy and token 2022 mints in one execute", async () => {
+        const legacyMint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const legacyForwardAta = await createAndFundAta(legacyMint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        const legacyDestinationAta = getAssociatedTokenAddressSync(legacyMint, destination.publicKey);
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [], () => []);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID,
+                legacyMint, legacyForwardAta, legacyDestinationAta,
+                mint, TOKEN_2022_PROGRAM_ID, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(legacyDestinationAta)).value.uiAmount, "legacy destination").to.equal(tokenAmount);
+        expect((await connection.getTokenAccountBalance(destinationAta)).value.uiAmount, "token 2022 destination").to.equal(tokenAmount);
+    });
+
+    it("Should not forward a mint of the other token program without its token program", async () => {
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [], () => []);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const destinationAta = getAssociatedTokenAddressSync(mint, destination.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            expect(e.message).to.contain("incorrect program id for instruction")
+            return;
+        }
+        expect.fail("Should not have executed")
+    });
+
+    it("The destination account should not be an ATA with extensions", async () => {
+        const mint = await createMintWithExtensions(connection, payer, mintAuthority.publicKey, [ExtensionType.TransferFeeConfig], mint =>
+            [createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, mintAuthority.publicKey, 100, BigInt(tokenAmount), TOKEN_2022_PROGRAM_ID)]);
+        const destinationAta = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection, TOKEN_2022_PROGRAM_ID);
+        const [ataForwardPda, ataForwardBump] = deriveForwardPda(destinationAta, forwardId, program.publicKey);
+        try {
+            await createForward(forwardId, destinationAta, payer.publicKey, ataForwardBump, ataForwardPda, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x1")
+            return;
+        }
+        expect.fail("Should not have created forward")
+    });
+});
@@ -0,0 +1,167 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {closeForward, createForward, deriveForwardPda, execute, executeWithTokens, setVesting} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {createMint, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createAndFundAta} from "./fns/createToken";
+import {Forward} from "./classes/classes";
+
+describe("vesting instruction tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const mintAuthority = Keypair.generate();
+    const forwardId = 123456;
+    const forwardAmount = LAMPORTS_PER_SOL / 100;
+    const oneDay = 24 * 60 * 60;
+
+    let destination, authority, forwardPda, forwardBump, now;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        authority = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, authority.publicKey, forwardBump, forwardPda, program, payer, connection);
+        await deposit(payer, forwardPda, forwardAmount, connection);
+        now = Math.floor(Date.now() / 1000);
+    });
+
+    it("Should not release sol before the vesting starts", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setVesting(forwardPda, authority, now + oneDay, now + 2 * oneDay, program, payer, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(0);
+    });
+
+    it("Should release the vested sol part way through the vesting", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const released = await connection.getBalance(destination.publicKey) - destinationBalanceBefore;
+        expect(released).to.be.within(forwardAmount * 0.45, forwardAmount * 0.55);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(Number(fwd.vesting.releasedLamports)).to.equal(released);
+    });
+
+    it("Should only release the vested tokens not yet released", async () => {
+        const tokenAmount = 1000;
+        const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+        const destinationAta = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection);
+        const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, mint, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        const released = (await connection.getTokenAccountBalance(destinationAta)).value.uiAmount;
+        expect(released).to.be.within(tokenAmount * 0.45, tokenAmount * 0.55);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(Buffer.from(fwd.vesting.releasedMints[0].mint)).to.deep.equal(mint.toBuffer());
+        expect(Number(fwd.vesting.releasedMints[0].amount)).to.equal(released);
+    });
+
+    it("Should leave a mint the vesting cannot track in the forward until the vesting ends", async () => {
+        const tokenAmount = 1000;
+        const tokenAccounts = [];
+        for (let i = 0; i < 6; i++) {
+            const mint = await createMint(connection, payer, mintAuthority.publicKey, null, 0);
+            const forwardAta = await createAndFundAta(mint, forwardPda, tokenAmount, payer, mintAuthority, connection);
+            const destinationAta = await createAndFundAta(mint, destination.publicKey, 0, payer, mintAuthority, connection);
+            tokenAccounts.push([mint, forwardAta, destinationAta]);
+        }
+        const [untrackedMint, untrackedForwardAta, untrackedDestinationAta] = tokenAccounts[5];
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, ...tokenAccounts.slice(0, 5).flat());
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, untrackedMint, untrackedForwardAta, untrackedDestinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(untrackedForwardAta)).value.uiAmount, "untracked mint").to.equal(tokenAmount);
+        const fwd = Forward.fromBuffer((await connection.getAccountInfo(forwardPda)).data);
+        expect(fwd.vesting.releasedMints.length, "released mints").to.equal(5);
+
+        try {
+            await setVesting(forwardPda, authority, now - 2 * oneDay, now - oneDay, program, payer, connection);
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, untrackedMint, untrackedForwardAta, untrackedDestinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect((await connection.getTokenAccountBalance(untrackedDestinationAta)).value.uiAmount, "untracked mint").to.equal(tokenAmount);
+    });
+
+    it("Should release everything once the vesting has ended", async () => {
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+            await setVesting(forwardPda, authority, now - 2 * oneDay, now - oneDay, program, payer, connection);
+            await execute(forwardPda, destination, program, payer, connection);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore).to.equal(forwardAmount);
+    });
+
+    it("Should not close a forward that is still vesting", async () => {
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+            await closeForward(forwardPda, destination, authority, payer.publicKey, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x15")
+            return;
+        }
+        expect.fail("Should not have closed")
+    });
+
+    it("Should not extend the vesting", async () => {
+        await setVesting(forwardPda, authority, now - oneDay, now + oneDay, program, payer, connection);
+        try {
+            await setVesting(forwardPda, authority, now - oneDay, now + 2 * oneDay, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x17")
+            return;
+        }
+        expect.fail("Should not have extended")
+    });
+
+    it("Should not set a vesting that ends before it starts", async () => {
+        try {
+            await setVesting(forwardPda, authority, now + oneDay, now, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0x17")
+            return;
+        }
+        expect.fail("Should not have set")
+    });
+
+    it("Should not set the vesting if not the authority", async () => {
+        try {
+            await setVesting(forwardPda, Keypair.generate(), now - oneDay, now + oneDay, program, payer, connection);
+        } catch (e) {
+            expect(e.message).to.contain("custom program error: 0xa")
+            return;
+        }
+        expect.fail("Should not have set")
+    });
+});
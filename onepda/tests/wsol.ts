@@ -0,0 +1,54 @@
+import {Connection, Keypair, LAMPORTS_PER_SOL} from '@solana/web3.js';
+import {deposit, initialiseAccountWithMinimumBalance} from "./fns/accounts";
+import {createForward, deriveForwardPda, executeWithTokens, executeWithTokensUnwrappingNative} from "./fns/forwardFns";
+import {createKeypairFromFile} from "./fns/createKeyPair";
+import {beforeEach} from "mocha";
+import {expect} from "chai";
+import {getAssociatedTokenAddressSync, NATIVE_MINT, TOKEN_PROGRAM_ID} from "@solana/spl-token";
+import {createATA} from "./fns/createToken";
+
+describe("wrapped sol tests", () => {
+
+    const connection = new Connection(`http://localhost:8899`, 'confirmed');
+    const payer = createKeypairFromFile(require('os').homedir() + '/.config/solana/id.json');
+    const program = createKeypairFromFile('./program/target/so/solana_forward-keypair.json');
+    const forwardId = 123456;
+    const wrapAmount = LAMPORTS_PER_SOL / 100;
+
+    let destination, forwardPda, forwardBump, forwardAta, destinationAta;
+
+    beforeEach("setup", async () => {
+        destination = Keypair.generate();
+        await initialiseAccountWithMinimumBalance(destination.publicKey, payer, connection);
+        [forwardPda, forwardBump] = deriveForwardPda(destination.publicKey, forwardId, program.publicKey);
+        await createForward(forwardId, destination, payer.publicKey, forwardBump, forwardPda, program, payer, connection);
+        forwardAta = await createATA(NATIVE_MINT, forwardPda, true, payer, connection);
+        destinationAta = getAssociatedTokenAddressSync(NATIVE_MINT, destination.publicKey);
+    });
+
+    it("Should unwrap wSOL and forward it as sol", async () => {
+        const forwardAtaRent = await connection.getBalance(forwardAta);
+        await deposit(payer, forwardAta, wrapAmount, connection);
+        const destinationBalanceBefore = await connection.getBalance(destination.publicKey);
+        try {
+            await executeWithTokensUnwrappingNative(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, NATIVE_MINT, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(await connection.getAccountInfo(forwardAta), "forward ata").to.be.null;
+        expect(await connection.getBalance(destination.publicKey) - destinationBalanceBefore, "destination").to.equal(wrapAmount + forwardAtaRent);
+    });
+
+    it("Should sync sol sent directly to the wSOL ATA and forward it as wSOL", async () => {
+        await deposit(payer, forwardAta, wrapAmount, connection);
+        try {
+            await executeWithTokens(forwardPda, destination, program, payer, connection, TOKEN_PROGRAM_ID, NATIVE_MINT, forwardAta, destinationAta);
+        } catch (e) {
+            console.log(e);
+            expect.fail("Should have executed");
+        }
+        expect(Number((await connection.getTokenAccountBalance(destinationAta)).value.amount), "destination").to.equal(wrapAmount);
+        expect(Number((await connection.getTokenAccountBalance(forwardAta)).value.amount), "forward").to.equal(0);
+    });
+});